@@ -0,0 +1,264 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate criterion;
+
+use snarkvm_circuit_algorithms::{BHP, BHP1024, BHP256, BHP512, BHP768, Hash, HashUncompressed};
+use snarkvm_circuit_types::{environment::Circuit, prelude::*};
+use snarkvm_utilities::{TestRng, Uniform};
+
+use criterion::Criterion;
+
+macro_rules! bench_bhp {
+    ($c:expr, $bhp:ident, $num_bits:expr) => {{
+        let rng = &mut TestRng::default();
+        let native = console::$bhp::<<Circuit as Environment>::Network>::setup(stringify!($bhp)).unwrap();
+        let input = (0..$num_bits).map(|_| bool::rand(rng)).collect::<Vec<_>>();
+
+        $c.bench_function(&format!("{} Circuit Hash - input size {}", stringify!($bhp), $num_bits), |b| {
+            b.iter(|| {
+                let hash = $bhp::<Circuit>::constant(native.clone());
+                let input: Vec<Boolean<_>> = Inject::new(Mode::Private, input.clone());
+                let _ = hash.hash(&input);
+                // Clear the circuit, so that the constraint system does not grow across iterations.
+                Circuit::reset();
+            })
+        });
+    }};
+}
+
+/// Returns the BHP hash of the given input in a single iteration, as in `BHP::hash_uncompressed`,
+/// while summing the chunks with the complete twisted Edwards addition of `Group`.
+fn hash_with_complete_addition<const NUM_WINDOWS: u8, const WINDOW_SIZE: u8>(
+    native: &console::BHP<<Circuit as Environment>::Network, NUM_WINDOWS, WINDOW_SIZE>,
+    input: &[Boolean<Circuit>],
+) -> Group<Circuit> {
+    // Construct the preimage as: [ 0...0 || DOMAIN || LENGTH(INPUT) || INPUT ].
+    let mut preimage = Vec::<Boolean<Circuit>>::constant(native.domain().to_vec());
+    U64::<Circuit>::constant(console::U64::new(input.len() as u64)).write_bits_le(&mut preimage);
+    preimage.extend_from_slice(input);
+    assert!(preimage.len() <= NUM_WINDOWS as usize * WINDOW_SIZE as usize * 3, "Expected a single iteration");
+    // Pad the preimage to a multiple of the chunk size.
+    preimage.resize((preimage.len() + 2) / 3 * 3, Boolean::constant(false));
+
+    let one = Field::<Circuit>::one();
+    let mut digest = Group::<Circuit>::zero();
+    for (bits, bases) in preimage.chunks(WINDOW_SIZE as usize * 3).zip(native.bases().iter()) {
+        for (chunk, base) in bits.chunks(3).zip(bases) {
+            // Compute the twisted Edwards coordinates of the lookups `[base, 2 * base, 3 * base, 4 * base]`.
+            let lookups = [*base, base.double(), base.double() + base, base.double().double()];
+            let x_bases = lookups.map(|lookup| Field::<Circuit>::constant(lookup.to_x_coordinate()));
+            let y_bases = lookups.map(|lookup| Field::<Circuit>::constant(lookup.to_y_coordinate()));
+
+            // Select the lookup with the first two bits.
+            let bit_0 = Field::from_boolean(&chunk[0]);
+            let bit_1 = Field::from_boolean(&chunk[1]);
+            let bit_0_and_1 = Field::from_boolean(&(&chunk[0] & &chunk[1]));
+            let select = |bases: &[Field<Circuit>; 4]| {
+                &bases[0]
+                    + &bit_0 * (&bases[1] - &bases[0])
+                    + &bit_1 * (&bases[2] - &bases[0])
+                    + &bit_0_and_1 * (&bases[3] - &bases[2] - &bases[1] + &bases[0])
+            };
+            // Negate the x-coordinate with the third bit.
+            let x = select(&x_bases) * (&one - Field::from_boolean(&chunk[2]).double());
+            let y = select(&y_bases);
+
+            // Sum the chunk with complete addition.
+            digest += Group::from_xy_coordinates_unchecked(x, y);
+        }
+    }
+    digest
+}
+
+fn bhp_addition(c: &mut Criterion) {
+    use console::HashUncompressed as _;
+
+    let rng = &mut TestRng::default();
+    let native = console::BHP256::<<Circuit as Environment>::Network>::setup("BHP256").unwrap();
+    let input = (0..256).map(|_| bool::rand(rng)).collect::<Vec<_>>();
+
+    // Ensure both addition paths compute the digest of the console BHP, and report their counts side by side.
+    // Note: The circuit is reset after the setup, so that the counts only include the hash.
+    let hash = BHP256::<Circuit>::constant(native.clone());
+    Circuit::reset();
+    let circuit_input: Vec<Boolean<_>> = Inject::new(Mode::Private, input.clone());
+    let expected = hash.hash_uncompressed(&circuit_input).eject_value();
+    let incomplete = Circuit::count();
+    Circuit::reset();
+    let circuit_input: Vec<Boolean<_>> = Inject::new(Mode::Private, input.clone());
+    let candidate = hash_with_complete_addition(&native, &circuit_input).eject_value();
+    let complete = Circuit::count();
+    Circuit::reset();
+    assert_eq!(expected, candidate, "The addition paths computed different digests");
+    assert_eq!(
+        native.hash_uncompressed(&input).unwrap(),
+        expected,
+        "The addition paths and the console BHP computed different digests"
+    );
+    println!("BHP256 (constants, public, private, constraints): incomplete {incomplete:?} vs complete {complete:?}");
+
+    c.bench_function("BHP256 Circuit Hash - incomplete addition", |b| {
+        b.iter(|| {
+            let input: Vec<Boolean<_>> = Inject::new(Mode::Private, input.clone());
+            let _ = hash.hash_uncompressed(&input);
+            Circuit::reset();
+        })
+    });
+
+    c.bench_function("BHP256 Circuit Hash - complete addition", |b| {
+        b.iter(|| {
+            let input: Vec<Boolean<_>> = Inject::new(Mode::Private, input.clone());
+            let _ = hash_with_complete_addition(&native, &input);
+            Circuit::reset();
+        })
+    });
+}
+
+fn bhp_native(c: &mut Criterion) {
+    use console::Hash as _;
+
+    let rng = &mut TestRng::default();
+    let native = console::BHP256::<<Circuit as Environment>::Network>::setup("BHP256").unwrap();
+    let input = (0..256).map(|_| bool::rand(rng)).collect::<Vec<_>>();
+    let hash = BHP256::<Circuit>::constant(native.clone());
+    Circuit::reset();
+
+    // Ensure the circuit computes the same digest as the console BHP.
+    let circuit_input: Vec<Boolean<_>> = Inject::new(Mode::Private, input.clone());
+    let candidate = hash.hash(&circuit_input).eject_value();
+    Circuit::reset();
+    assert_eq!(native.hash(&input).unwrap(), candidate, "The circuit and console BHP computed different digests");
+
+    c.bench_function("BHP256 Native Hash - input size 256", |b| {
+        b.iter(|| {
+            let _ = native.hash(&input);
+        })
+    });
+
+    c.bench_function("BHP256 Circuit Hash - private input size 256", |b| {
+        b.iter(|| {
+            let input: Vec<Boolean<_>> = Inject::new(Mode::Private, input.clone());
+            let _ = hash.hash(&input);
+            Circuit::reset();
+        })
+    });
+}
+
+fn bhp_modes(c: &mut Criterion) {
+    let rng = &mut TestRng::default();
+    let native = console::BHP512::<<Circuit as Environment>::Network>::setup("BHP512").unwrap();
+    let input = (0..512).map(|_| bool::rand(rng)).collect::<Vec<_>>();
+    let hash = BHP512::<Circuit>::constant(native);
+    Circuit::reset();
+
+    // Report the counts of hashing the same input in each mode, as a comparison table.
+    // Note: The counts include the injection of the input, which is part of the cost of a witnessed preimage.
+    println!("| BHP512 mode | constants | public | private | constraints |");
+    println!("|-------------|-----------|--------|---------|-------------|");
+    for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+        let snapshot = Circuit::snapshot();
+        let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input.clone());
+        let _ = hash.hash(&circuit_input);
+        let count = snapshot.diff();
+        Circuit::reset();
+        println!(
+            "| {:<11} | {:>9} | {:>6} | {:>7} | {:>11} |",
+            mode.to_string(),
+            count.num_constants,
+            count.num_public,
+            count.num_private,
+            count.num_constraints
+        );
+    }
+
+    // Time the witness generation of the hash in each mode.
+    for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+        c.bench_function(&format!("BHP512 Circuit Hash - {mode} input size 512"), |b| {
+            b.iter(|| {
+                let input: Vec<Boolean<_>> = Inject::new(mode, input.clone());
+                let _ = hash.hash(&input);
+                Circuit::reset();
+            })
+        });
+    }
+}
+
+fn bhp_constraints_by_length(c: &mut Criterion) {
+    const NUM_WINDOWS: u8 = 32;
+    const WINDOW_SIZE: u8 = 48;
+
+    let rng = &mut TestRng::default();
+    let native = console::BHP::<<Circuit as Environment>::Network, NUM_WINDOWS, WINDOW_SIZE>::setup("BHP").unwrap();
+    let hash = BHP::<Circuit, NUM_WINDOWS, WINDOW_SIZE>::constant(native);
+    Circuit::reset();
+
+    // The sizes of the preimage of one iteration, which is the 252-bit prefix followed by the input.
+    let num_prefix_bits = <Circuit as Environment>::BaseField::size_in_data_bits();
+    let max_preimage_bits = NUM_WINDOWS as usize * WINDOW_SIZE as usize * 3;
+    let max_input_bits = max_preimage_bits - num_prefix_bits;
+
+    // Report the number of constraints of a private input of each length in one iteration, one chunk apart,
+    // as a CSV table. The cost is linear in the chunks within a window, with a fixed cost per window.
+    // Note: The counts include the 1 boolean constraint per bit of the injected input.
+    println!("num_input_bits,num_chunks,num_windows,num_constraints");
+    for num_bits in (0..=max_input_bits).step_by(3) {
+        let input = (0..num_bits).map(|_| bool::rand(rng)).collect::<Vec<_>>();
+        let circuit_input: Vec<Boolean<_>> = Inject::new(Mode::Private, input);
+        let _ = hash.hash(&circuit_input);
+        let num_preimage_bits = num_prefix_bits + num_bits;
+        println!(
+            "{num_bits},{},{},{}",
+            (num_preimage_bits + 2) / 3,
+            (num_preimage_bits + WINDOW_SIZE as usize * 3 - 1) / (WINDOW_SIZE as usize * 3),
+            Circuit::num_constraints()
+        );
+        Circuit::reset();
+    }
+
+    // Time the hash of the largest input in one iteration.
+    let input = (0..max_input_bits).map(|_| bool::rand(rng)).collect::<Vec<_>>();
+    c.bench_function(&format!("BHP<{NUM_WINDOWS}, {WINDOW_SIZE}> Circuit Hash - input size {max_input_bits}"), |b| {
+        b.iter(|| {
+            let input: Vec<Boolean<_>> = Inject::new(Mode::Private, input.clone());
+            let _ = hash.hash(&input);
+            Circuit::reset();
+        })
+    });
+}
+
+fn bhp256(c: &mut Criterion) {
+    bench_bhp!(c, BHP256, 256);
+}
+
+fn bhp512(c: &mut Criterion) {
+    bench_bhp!(c, BHP512, 512);
+}
+
+fn bhp768(c: &mut Criterion) {
+    bench_bhp!(c, BHP768, 768);
+}
+
+fn bhp1024(c: &mut Criterion) {
+    bench_bhp!(c, BHP1024, 1024);
+}
+
+criterion_group! {
+    name = bhp;
+    config = Criterion::default().sample_size(10);
+    targets = bhp256, bhp512, bhp768, bhp1024, bhp_addition, bhp_native, bhp_modes, bhp_constraints_by_length
+}
+
+criterion_main!(bhp);
@@ -0,0 +1,116 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Asserts that the given preimage satisfies the given canonicity rule.
+    ///
+    /// BHP is well-defined on arbitrary bit strings, and does not require its input to be the canonical encoding
+    /// of any value. For example, it hashes the bits of a non-canonical field element (i.e. one that is at least
+    /// the modulus) differently than those of the canonical field element it reduces to. Callers that require
+    /// a canonical preimage should enforce their own rule on the bits with this method before hashing.
+    pub fn assert_canonical_preimage<F: FnOnce(&[Boolean<E>]) -> Boolean<E>>(
+        &self,
+        input: &[Boolean<E>],
+        is_canonical: F,
+    ) {
+        E::assert(is_canonical(input));
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "BHPCircuit0";
+
+    type NativeField = console::Field<<Circuit as Environment>::Network>;
+
+    /// Returns the canonical and a non-canonical encoding of the zero field element.
+    fn zero_encodings() -> (Vec<bool>, Vec<bool>) {
+        use console::ToBits as TB;
+
+        // The canonical encoding of zero is all zeros.
+        let canonical = vec![false; NativeField::size_in_bits()];
+        // The encoding of the modulus reduces to zero, and is obtained by adding 1 to the bits of `MODULUS - 1`.
+        let mut non_canonical = (-NativeField::one()).to_bits_le();
+        assert!(!non_canonical[0], "The modulus is odd, so `MODULUS - 1` must be even");
+        non_canonical[0] = true;
+        (canonical, non_canonical)
+    }
+
+    /// Returns `true` if the given bits are the canonical encoding of a field element.
+    fn is_canonical_field(bits_le: &[Boolean<Circuit>]) -> Boolean<Circuit> {
+        use console::ToBits as TB;
+        Boolean::is_less_than_or_equal_constant(bits_le, &(-NativeField::one()).to_bits_le())
+    }
+
+    #[test]
+    fn test_hash_is_well_defined_on_non_canonical_bits() -> Result<()> {
+        use console::Hash as H;
+
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+
+        let (canonical, non_canonical) = zero_encodings();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Ensure the circuit matches the native hash on both encodings.
+            let candidates = [&canonical, &non_canonical].map(|input| {
+                let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input.clone());
+                let candidate = circuit.hash(&circuit_input).eject_value();
+                assert_eq!(native.hash(input).unwrap(), candidate);
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+                candidate
+            });
+            // Ensure the encodings hash to different digests, as BHP hashes bits, not values.
+            assert_ne!(candidates[0], candidates[1]);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_assert_canonical_preimage() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native);
+
+        let (canonical, non_canonical) = zero_encodings();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Ensure the canonical encoding is accepted.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, canonical.clone());
+            circuit.assert_canonical_preimage(&circuit_input, is_canonical_field);
+            circuit.hash(&circuit_input);
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+
+            // Ensure the non-canonical encoding is rejected.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, non_canonical.clone());
+            let result = std::panic::catch_unwind(|| {
+                circuit.assert_canonical_preimage(&circuit_input, is_canonical_field);
+                circuit.hash(&circuit_input);
+            });
+            assert!(result.is_err() || !Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+}
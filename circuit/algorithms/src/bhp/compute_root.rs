@@ -0,0 +1,155 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the root of the Merkle tree with the given leaves, computed bottom-up with `merge`.
+    ///
+    /// If the number of leaves is not a power of two, the leaves are padded up to the next power of two
+    /// with the empty hash, `merge(0, 0)`, which matches the padding of the native Merkle tree.
+    /// As the empty hash is a constant, each padded leaf adds no constraints (but each merge with it does).
+    ///
+    /// This method halts if the given leaves are empty.
+    pub fn compute_root(&self, leaves: &[Field<E>]) -> Field<E> {
+        // Ensure there is at least one leaf.
+        if leaves.is_empty() {
+            E::halt("Cannot compute the Merkle root of an empty set of leaves")
+        }
+
+        // Initialize the level of nodes with the leaves.
+        let mut nodes = leaves.to_vec();
+        // Pad the leaves up to the next power of two with the empty hash.
+        if !nodes.len().is_power_of_two() {
+            let empty_hash = self.merge(&Field::zero(), &Field::zero()); // 0 constraints
+            nodes.resize(nodes.len().next_power_of_two(), empty_hash);
+        }
+
+        // Merge each pair of nodes, until the root remains.
+        while nodes.len() > 1 {
+            nodes = nodes.chunks(2).map(|pair| self.merge(&pair[0], &pair[1])).collect();
+        }
+        nodes.swap_remove(0)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "BHPCircuit0";
+
+    type NativeField = console::Field<<Circuit as Environment>::Network>;
+
+    /// Returns the native root of the Merkle tree with the given leaves, padded with the native empty hash.
+    fn native_root(
+        native: &console::BHP512<<Circuit as Environment>::Network>,
+        leaves: &[NativeField],
+    ) -> Result<NativeField> {
+        use console::{Hash as H, ToBits as TB};
+
+        // Returns the native BHP hash of the given left and right digests.
+        let merge = |left: &NativeField, right: &NativeField| {
+            let mut preimage = vec![true];
+            left.write_bits_le(&mut preimage);
+            right.write_bits_le(&mut preimage);
+            native.hash(&preimage)
+        };
+
+        let mut nodes = leaves.to_vec();
+        nodes.resize(leaves.len().next_power_of_two(), merge(&NativeField::zero(), &NativeField::zero())?);
+        while nodes.len() > 1 {
+            nodes = nodes.chunks(2).map(|pair| merge(&pair[0], &pair[1])).collect::<Result<_>>()?;
+        }
+        Ok(nodes[0])
+    }
+
+    fn check_compute_root(
+        mode: Mode,
+        num_leaves: usize,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+        Circuit::reset();
+
+        let mut rng = TestRng::default();
+
+        // Sample the leaves.
+        let leaves = (0..num_leaves).map(|_| Uniform::rand(&mut rng)).collect::<Vec<NativeField>>();
+        // Compute the expected root.
+        let expected = native_root(&native, &leaves)?;
+        // Prepare the circuit leaves.
+        let circuit_leaves = leaves.iter().map(|leaf| Field::new(mode, *leaf)).collect::<Vec<_>>();
+
+        Circuit::scope(format!("BHP {mode} {num_leaves}"), || {
+            let candidate = circuit.compute_root(&circuit_leaves);
+            assert_scope!(num_constants, num_public, num_private, num_constraints);
+            assert_eq!(expected, candidate.eject_value());
+        });
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_compute_root_constant() -> Result<()> {
+        check_compute_root(Mode::Constant, 4, 1524, 0, 0, 0)?;
+        check_compute_root(Mode::Constant, 8, 3556, 0, 0, 0)
+    }
+
+    #[test]
+    fn test_compute_root_public() -> Result<()> {
+        check_compute_root(Mode::Public, 4, 1227, 0, 5637, 5649)?;
+        check_compute_root(Mode::Public, 8, 2863, 0, 13153, 13181)
+    }
+
+    #[test]
+    fn test_compute_root_private() -> Result<()> {
+        check_compute_root(Mode::Private, 4, 1227, 0, 5637, 5649)?;
+        check_compute_root(Mode::Private, 8, 2863, 0, 13153, 13181)
+    }
+
+    #[test]
+    fn test_compute_root_pads_with_empty_hash() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+
+        let mut rng = TestRng::default();
+
+        for num_leaves in [1, 3, 5, 7] {
+            // Sample the leaves.
+            let leaves = (0..num_leaves).map(|_| Uniform::rand(&mut rng)).collect::<Vec<NativeField>>();
+            let expected = native_root(&native, &leaves)?;
+
+            for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                let circuit_leaves = leaves.iter().map(|leaf| Field::new(mode, *leaf)).collect::<Vec<_>>();
+                let candidate = circuit.compute_root(&circuit_leaves);
+                assert_eq!(expected, candidate.eject_value(), "(mode = {mode}, num_leaves = {num_leaves})");
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,170 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use core::cell::RefCell;
+use std::rc::Rc;
+
+/// A map applied to the point of each window of an iteration, as `f(window_index, window_point)`.
+pub(super) type WindowMap<'a, E> = &'a dyn Fn(usize, Group<E>) -> Group<E>;
+
+/// A trace of the windows evaluated by the BHP hashes in a context.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct BHPTrace {
+    /// Whether a digest was computed by the native BHP, in which case no window was evaluated for it.
+    pub(crate) is_native: bool,
+    /// For each iteration, whether each window was constant-folded (`true`) or constrained (`false`).
+    pub(crate) windows: Vec<Vec<bool>>,
+    /// The number of chunks with a non-constant bit.
+    pub(crate) chunks: u64,
+    /// The number of Montgomery additions with a non-constant operand.
+    pub(crate) montgomery_adds: u64,
+    /// The number of field divisions with a non-constant operand.
+    pub(crate) field_inversions: u64,
+}
+
+/// A shared context of the constants used by each BHP hasher.
+///
+/// By default, each iteration of a BHP hash allocates its own constants. When several BHP hashes
+/// (of the same or different parameterizations) are used in one circuit, hashing them in a shared
+/// context allocates these constants once.
+#[derive(Clone)]
+pub struct BHPContext<'a, E: Environment> {
+    /// The 1/2 constant field element.
+    pub(super) one_half: Field<E>,
+    /// The constant coefficient A for the Montgomery curve.
+    pub(super) coeff_a: Field<E>,
+    /// The constant coefficient B for the Montgomery curve.
+    pub(super) coeff_b: Field<E>,
+    /// The map applied to the point of each window, if any.
+    pub(super) window_map: Option<WindowMap<'a, E>>,
+    /// The trace of the windows evaluated in this context, if it is recorded.
+    pub(super) trace: Option<Rc<RefCell<BHPTrace>>>,
+}
+
+impl<'a, E: Environment> BHPContext<'a, E> {
+    /// Initializes a new BHP context.
+    pub fn new() -> Self {
+        Self {
+            one_half: Field::constant(console::Field::<E::Network>::half()),
+            coeff_a: Field::constant(console::Group::<E::Network>::MONTGOMERY_A),
+            coeff_b: Field::constant(console::Group::<E::Network>::MONTGOMERY_B),
+            window_map: None,
+            trace: None,
+        }
+    }
+
+    /// Initializes a new BHP context, which applies the given map to the point of each window.
+    pub(super) fn with_window_map(window_map: WindowMap<'a, E>) -> Self {
+        Self { window_map: Some(window_map), ..Self::new() }
+    }
+
+    /// Initializes a new BHP context, which records a trace of the windows it evaluates.
+    pub(super) fn with_trace() -> Self {
+        Self { trace: Some(Default::default()), ..Self::new() }
+    }
+
+    /// Returns the trace of the windows evaluated in this context, if it is recorded.
+    pub(super) fn trace(&self) -> Option<BHPTrace> {
+        self.trace.as_ref().map(|trace| trace.borrow().clone())
+    }
+
+    /// Updates the trace with the given function, if it is recorded.
+    pub(super) fn record(&self, f: impl FnOnce(&mut BHPTrace)) {
+        if let Some(trace) = &self.trace {
+            f(&mut trace.borrow_mut())
+        }
+    }
+}
+
+impl<E: Environment> Default for BHPContext<'_, E> {
+    /// Initializes a new BHP context.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the BHP hash of the given input as a field element, using the constants of the given shared context.
+    pub fn hash_in_context(&self, context: &BHPContext<'_, E>, input: &[Boolean<E>]) -> Field<E> {
+        self.hash_uncompressed_in_context(context, input).to_x_coordinate()
+    }
+
+    /// Returns the BHP hash of the given input as an affine group element, using the constants of the given shared context.
+    pub fn hash_uncompressed_in_context(&self, context: &BHPContext<'_, E>, input: &[Boolean<E>]) -> Group<E> {
+        match self.try_hash_iterations(Some(context), input) {
+            Ok(digest) => digest,
+            Err(error) => E::halt(error.to_string()),
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+    const DOMAIN: &str = "BHPCircuit0";
+
+    #[test]
+    fn test_hash_in_shared_context() -> Result<()> {
+        use console::Hash as H;
+
+        // Initialize two BHP parameterizations.
+        let native_256 = console::BHP256::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let native_512 = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit_256 = BHP256::<Circuit>::constant(native_256.clone());
+        let circuit_512 = BHP512::<Circuit>::constant(native_512.clone());
+
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Public, Mode::Private] {
+            for i in 0..ITERATIONS {
+                // Sample a random input, which takes 2 iterations for BHP256 and 1 iteration for BHP512.
+                let input = (0..300).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+                let expected_256 = native_256.hash(&input)?;
+                let expected_512 = native_512.hash(&input)?;
+                let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+                // Hash the input with each parameterization independently.
+                let independent = Circuit::scope(format!("Independent {mode} {i}"), || {
+                    assert_eq!(expected_256, circuit_256.hash(&circuit_input).eject_value());
+                    assert_eq!(expected_512, circuit_512.hash(&circuit_input).eject_value());
+                    Circuit::count_in_scope()
+                });
+                Circuit::reset();
+
+                // Hash the input with both parameterizations in a shared context.
+                Circuit::scope(format!("Shared {mode} {i}"), || {
+                    let context = BHPContext::new();
+                    assert_eq!(expected_256, circuit_256.hash_in_context(&context, &circuit_input).eject_value());
+                    assert_eq!(expected_512, circuit_512.hash_in_context(&context, &circuit_input).eject_value());
+                    assert!(Circuit::is_satisfied_in_scope());
+
+                    // Ensure the shared context saves 3 constants for each of the 2 iterations after the first.
+                    let shared = Circuit::count_in_scope();
+                    assert_eq!(independent.0 - 6, shared.0);
+                    assert_eq!((independent.1, independent.2, independent.3), (shared.1, shared.2, shared.3));
+                });
+                Circuit::reset();
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,255 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A BHP digest, which is the x-coordinate of the BHP hash output.
+///
+/// This newtype distinguishes a BHP digest from an arbitrary field element.
+/// To use the digest as a field element, call `as_field` or `into_field`.
+#[derive(Clone)]
+pub struct BHPDigest<E: Environment>(Field<E>);
+
+impl<E: Environment> BHPDigest<E> {
+    /// Initializes a new BHP digest from the given x-coordinate of the BHP hash output.
+    pub(super) const fn new(field: Field<E>) -> Self {
+        Self(field)
+    }
+
+    /// Returns a reference to the digest as a field element.
+    pub const fn as_field(&self) -> &Field<E> {
+        &self.0
+    }
+
+    /// Returns the digest as a field element.
+    pub fn into_field(self) -> Field<E> {
+        self.0
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment> BHPDigest<E> {
+    /// Returns the digest as a native field element, for storage outside of the circuit.
+    pub fn to_native(&self) -> console::Field<E::Network> {
+        self.0.eject_value()
+    }
+
+    /// Initializes a digest from the given native field element in the given mode,
+    /// to reinject a stored digest in a later circuit.
+    ///
+    /// Note: The reinjected digest is not constrained to be the output of a hash,
+    /// so it should be enforced against a recomputed digest (e.g. with `BHP::enforce_hash`).
+    pub fn from_native(field: console::Field<E::Network>, mode: Mode) -> Self {
+        Self(Field::new(mode, field))
+    }
+}
+
+impl<E: Environment> From<BHPDigest<E>> for Field<E> {
+    /// Returns the digest as a field element.
+    fn from(digest: BHPDigest<E>) -> Self {
+        digest.into_field()
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment> Eject for BHPDigest<E> {
+    type Primitive = console::Field<E::Network>;
+
+    /// Ejects the mode of the digest.
+    fn eject_mode(&self) -> Mode {
+        self.0.eject_mode()
+    }
+
+    /// Ejects the digest as a field element.
+    fn eject_value(&self) -> Self::Primitive {
+        self.0.eject_value()
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment> Debug for BHPDigest<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment> Display for BHPDigest<E> {
+    /// Prints the digest as the big-endian hex encoding of its x-coordinate, e.g. `0x05fe...02`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let bytes = console::ToBytes::to_bytes_le(&self.eject_value()).map_err(|_| fmt::Error)?;
+        write!(f, "0x")?;
+        bytes.iter().rev().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+impl<E: Environment> Equal<Self> for BHPDigest<E> {
+    type Output = Boolean<E>;
+
+    /// Returns `true` if `self` and `other` are equal.
+    fn is_equal(&self, other: &Self) -> Self::Output {
+        self.0.is_equal(&other.0)
+    }
+
+    /// Returns `true` if `self` and `other` are *not* equal.
+    fn is_not_equal(&self, other: &Self) -> Self::Output {
+        self.0.is_not_equal(&other.0)
+    }
+}
+
+impl<E: Environment> ToBits for BHPDigest<E> {
+    type Boolean = Boolean<E>;
+
+    /// Outputs the little-endian bit representation of the digest *without* trailing zeros.
+    fn write_bits_le(&self, vec: &mut Vec<Self::Boolean>) {
+        self.0.write_bits_le(vec);
+    }
+
+    /// Outputs the big-endian bit representation of the digest *without* leading zeros.
+    fn write_bits_be(&self, vec: &mut Vec<Self::Boolean>) {
+        self.0.write_bits_be(vec);
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "BHPCircuit0";
+
+    #[test]
+    fn test_digest_native_round_trip() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native);
+
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Sample a random input, and store its digest natively.
+            let input = (0..522).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input.clone());
+            let stored = circuit.hash_digest(&circuit_input).to_native();
+            Circuit::reset();
+
+            // Reinject the stored digest, and ensure it equals the recomputed digest in a fresh scope.
+            Circuit::scope(format!("BHP {mode}"), || {
+                let digest = BHPDigest::<Circuit>::from_native(stored, mode);
+                assert_eq!(mode, digest.eject_mode());
+                assert_eq!(stored, digest.to_native());
+
+                let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+                circuit.enforce_hash(&circuit_input, digest.as_field());
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_digest_conversions() -> Result<()> {
+        use console::{Hash as H, ToBits as TB};
+
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Sample a random input.
+            let input = (0..522).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+            let expected = native.hash(&input)?;
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+            // Ensure the digest and its conversions match the raw field digest.
+            let digest = circuit.hash_digest(&circuit_input);
+            let field = circuit.hash_field(&circuit_input);
+            assert_eq!(expected, digest.eject_value());
+            assert_eq!(expected, digest.as_field().eject_value());
+            assert_eq!(expected, digest.clone().into_field().eject_value());
+            assert_eq!(expected, Field::from(digest.clone()).eject_value());
+            assert_eq!(expected, field.eject_value());
+            assert_eq!(expected.to_bits_le(), digest.to_bits_le().eject_value());
+            assert_eq!(field.eject_mode(), digest.eject_mode());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_digest_display() -> Result<()> {
+        use console::ToBytes;
+
+        /// The digest of the fixed input of `test_hash_regression`, as a big-endian hex string.
+        const EXPECTED: &str = "0x05fe25ad4933fbfcf6ebc3f1f7cd5488eb9180eb1b7bcdd4cffd97bac1c33702";
+
+        // Initialize BHP.
+        let native = console::BHP256::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP256::<Circuit>::constant(native);
+
+        // Hash a fixed input, with every third bit set.
+        let input = (0..261).map(|i| i % 3 == 0).collect::<Vec<bool>>();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input.clone());
+            let digest = circuit.hash_digest(&circuit_input);
+
+            // Ensure the digest is rendered as the hex of the native field element.
+            let native_hex =
+                digest.to_native().to_bytes_le()?.iter().rev().map(|byte| format!("{byte:02x}")).collect::<String>();
+            assert_eq!(format!("0x{native_hex}"), format!("{digest}"));
+            assert_eq!(EXPECTED, format!("{digest}"));
+            assert_eq!(EXPECTED, format!("{digest:?}"));
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_digest_equality() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native);
+
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Sample two distinct random inputs.
+            let first = (0..522).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+            let mut second = first.clone();
+            second[0] = !second[0];
+            let first: Vec<Boolean<_>> = Inject::new(mode, first);
+            let second: Vec<Boolean<_>> = Inject::new(mode, second);
+
+            // Ensure equal inputs yield equal digests, and distinct inputs yield distinct digests.
+            let a = circuit.hash_digest(&first);
+            let b = circuit.hash_digest(&first);
+            let c = circuit.hash_digest(&second);
+            assert!(a.is_equal(&b).eject_value());
+            assert!(!a.is_not_equal(&b).eject_value());
+            assert!(!a.is_equal(&c).eject_value());
+            assert!(a.is_not_equal(&c).eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+}
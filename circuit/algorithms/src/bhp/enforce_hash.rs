@@ -0,0 +1,124 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Enforces that the BHP hash of the given input is equal to the given expected digest.
+    ///
+    /// This is the cheapest way to check a preimage against a known digest (e.g. a public input),
+    /// as it adds a single equality constraint instead of constructing a `Boolean` result.
+    pub fn enforce_hash(&self, input: &[Boolean<E>], expected: &Field<E>) {
+        E::assert_eq(self.hash_field(input), expected);
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+    const DOMAIN: &str = "BHPCircuit0";
+
+    fn check_enforce_hash(
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) -> Result<()> {
+        use console::Hash as H;
+
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random input.
+            let input = (0..522).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+            // Compute the expected digest.
+            let expected = native.hash(&input)?;
+            // Prepare the circuit input and digest.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input.clone());
+            let circuit_expected = Field::new(mode, expected);
+
+            Circuit::scope(format!("BHP {mode} {i}"), || {
+                circuit.enforce_hash(&circuit_input, &circuit_expected);
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+
+            // Ensure an incorrect digest is not satisfied.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+            let circuit_expected = Field::new(mode, expected + console::Field::one());
+            let result = std::panic::catch_unwind(|| circuit.enforce_hash(&circuit_input, &circuit_expected));
+            assert!(result.is_err() || !Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_enforce_hash_collects_failures() -> Result<()> {
+        use console::Hash as H;
+
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Sample a random input, and compute an incorrect digest.
+            let input = (0..522).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+            let (expected, one) = (native.hash(&input)?, console::Field::one());
+            let incorrect = expected + one;
+            // Prepare the circuit input and the broken digest witness.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+            let circuit_expected = Field::new(mode, incorrect);
+
+            // Ensure the failing enforcement is reported, instead of halting.
+            let ((), failures) = Circuit::collect_failures(|| {
+                Circuit::scope("BHP enforce_hash", || circuit.enforce_hash(&circuit_input, &circuit_expected))
+            });
+            assert_eq!(1, failures.len(), "(mode = {mode})");
+            // Ensure the reported failure is the equality `hash == expected`, as `(hash * 1) != expected`.
+            let constraint = format!("({} * {}) != {}", *expected, *one, *incorrect);
+            assert!(failures[0].ends_with(&constraint), "(mode = {mode}) {}", failures[0]);
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_enforce_hash_constant() -> Result<()> {
+        check_enforce_hash(Mode::Constant, 2, 0, 0, 0)
+    }
+
+    #[test]
+    fn test_enforce_hash_public() -> Result<()> {
+        check_enforce_hash(Mode::Public, 409, 0, 895, 896)
+    }
+
+    #[test]
+    fn test_enforce_hash_private() -> Result<()> {
+        check_enforce_hash(Mode::Private, 409, 0, 895, 896)
+    }
+}
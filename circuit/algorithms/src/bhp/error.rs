@@ -0,0 +1,53 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use thiserror::Error;
+
+/// The errors that may occur when hashing with BHP.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum BHPError {
+    #[error(
+        "Inputs to this BHP must be greater than {min_bits} bits, found {num_bits} bits ({num_missing_bits} more required)"
+    )]
+    InputTooShort { num_bits: usize, min_bits: usize, num_missing_bits: usize },
+
+    #[error("Inputs to this BHP cannot exceed {max_bits} bits")]
+    InputTooLong { num_bits: usize, max_bits: usize },
+
+    #[error(
+        "The BHP parameters ({num_windows} windows of size {window_size}) overflow or cannot fit an iteration of {num_data_bits} data bits"
+    )]
+    InvalidParameters { num_windows: usize, window_size: usize, num_data_bits: usize },
+
+    #[error("The BHP bases must have {expected} windows, found {num_windows} windows")]
+    InvalidNumWindows { num_windows: usize, expected: usize },
+
+    #[error("The BHP bases must have {expected} bases in each window, found {window_size} bases in window {window}")]
+    InvalidWindowSize { window: usize, window_size: usize, expected: usize },
+
+    #[error("The BHP random base must have {expected} bases, found {num_bases} bases")]
+    InvalidRandomBase { num_bases: usize, expected: usize },
+
+    #[error("The BHP bases must be constants")]
+    NonConstantBases,
+
+    #[error("The BHP base {base} in window {window} is the identity")]
+    IdentityBase { window: usize, base: usize },
+
+    #[error("The BHP base {base} in window {window} is degenerate, its first {num_powers} powers are not distinct")]
+    DegenerateBase { window: usize, base: usize, num_powers: usize },
+
+    #[error("Invalid BHP domain: {message}")]
+    InvalidDomain { message: String },
+}
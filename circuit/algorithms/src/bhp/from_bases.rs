@@ -0,0 +1,144 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Initializes a new instance of a BHP circuit from the given domain and the given generators.
+    ///
+    /// This allows loading pinned generators (e.g. deserialized ones), instead of deriving them in `setup`.
+    /// The `bases` must be `NUM_WINDOWS` windows of `WINDOW_SIZE` bases each, and the `random_base` must have
+    /// one base per scalar bit. The generators must be constants, as the base lookups are computed natively.
+    pub fn from_bases(domain: &str, bases: Vec<Vec<Group<E>>>, random_base: Vec<Group<E>>) -> Result<Self, BHPError> {
+        // Ensure the bases have the expected shape.
+        if bases.len() != NUM_WINDOWS as usize {
+            return Err(BHPError::InvalidNumWindows { num_windows: bases.len(), expected: NUM_WINDOWS as usize });
+        }
+        if let Some((window, window_bases)) =
+            bases.iter().enumerate().find(|(_, window_bases)| window_bases.len() != WINDOW_SIZE as usize)
+        {
+            let (window_size, expected) = (window_bases.len(), WINDOW_SIZE as usize);
+            return Err(BHPError::InvalidWindowSize { window, window_size, expected });
+        }
+        let expected = console::Scalar::<E::Network>::size_in_bits();
+        if random_base.len() != expected {
+            return Err(BHPError::InvalidRandomBase { num_bases: random_base.len(), expected });
+        }
+        // Ensure the generators are constants.
+        if !bases.iter().flatten().chain(&random_base).all(|base| base.is_constant()) {
+            return Err(BHPError::NonConstantBases);
+        }
+
+        // Convert the domain into the domain separator, as in the native `setup`.
+        let domain = console::BHP::<E::Network, NUM_WINDOWS, WINDOW_SIZE>::domain_bits(domain)
+            .map_err(|error| BHPError::InvalidDomain { message: error.to_string() })?;
+
+        // Initialize the BHP hasher from the native generators.
+        let bases = bases.iter().map(|window| window.eject_value()).collect::<Vec<_>>();
+        let hasher = BHPHasher::<E, NUM_WINDOWS, WINDOW_SIZE>::try_from_bases(&bases, &random_base.eject_value())?;
+
+        Ok(Self { domain: Vec::constant(domain), hasher, native: None })
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "BHPCircuit0";
+
+    type Native = console::BHP512<<Circuit as Environment>::Network>;
+
+    /// Returns the generators of the given native BHP, injected as constants.
+    fn constant_generators(native: &Native) -> (Vec<Vec<Group<Circuit>>>, Vec<Group<Circuit>>) {
+        let bases = native.bases().iter().map(|window| Inject::constant(window.clone())).collect();
+        let random_base = Inject::constant(native.random_base().to_vec());
+        (bases, random_base)
+    }
+
+    #[test]
+    fn test_from_bases() -> Result<()> {
+        let native = Native::setup(DOMAIN)?;
+        let (bases, random_base) = constant_generators(&native);
+
+        // Ensure the BHP from explicit generators matches the BHP from `setup`.
+        let circuit = BHP512::<Circuit>::from_bases(DOMAIN, bases, random_base)?;
+        let expected = BHP512::<Circuit>::constant(native.clone());
+
+        let mut rng = TestRng::default();
+        let input = (0..512).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+        let expected_digest = console::HashUncompressed::hash_uncompressed(&native, &input)?.to_x_coordinate();
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let candidate = circuit.hash_native_bits(&input, mode);
+            assert_eq!(expected_digest, candidate.eject_value());
+            assert_eq!(expected.hash_native_bits(&input, mode).eject_value(), candidate.eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_bases_fails_on_invalid_shape() -> Result<()> {
+        let native = Native::setup(DOMAIN)?;
+
+        // Ensure a wrong number of windows is an error.
+        let (mut bases, random_base) = constant_generators(&native);
+        bases.pop();
+        let expected = BHPError::InvalidNumWindows { num_windows: 5, expected: 6 };
+        assert_eq!(Some(expected), BHP512::<Circuit>::from_bases(DOMAIN, bases, random_base).err());
+
+        // Ensure a wrong number of bases in a window is an error.
+        let (mut bases, random_base) = constant_generators(&native);
+        bases[2].pop();
+        let expected = BHPError::InvalidWindowSize { window: 2, window_size: 42, expected: 43 };
+        assert_eq!(Some(expected), BHP512::<Circuit>::from_bases(DOMAIN, bases, random_base).err());
+
+        // Ensure a wrong number of random bases is an error.
+        let (bases, mut random_base) = constant_generators(&native);
+        random_base.truncate(1);
+        let expected = BHPError::InvalidRandomBase { num_bases: 1, expected: 251 };
+        assert_eq!(Some(expected), BHP512::<Circuit>::from_bases(DOMAIN, bases, random_base).err());
+
+        // Ensure non-constant generators are an error.
+        let (mut bases, random_base) = constant_generators(&native);
+        bases[0][0] = Group::new(Mode::Private, native.bases()[0][0]);
+        let expected = BHPError::NonConstantBases;
+        assert_eq!(Some(expected), BHP512::<Circuit>::from_bases(DOMAIN, bases, random_base).err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_bases_fails_on_invalid_generators() -> Result<()> {
+        let native = Native::setup(DOMAIN)?;
+
+        // Ensure an identity base is an error.
+        let (mut bases, random_base) = constant_generators(&native);
+        bases[1][3] = Group::zero();
+        let expected = BHPError::IdentityBase { window: 1, base: 3 };
+        assert_eq!(Some(expected), BHP512::<Circuit>::from_bases(DOMAIN, bases, random_base).err());
+
+        // Ensure a domain exceeding the maximum size is an error, without halting.
+        let (bases, random_base) = constant_generators(&native);
+        let domain = "A".repeat(24);
+        let expected = BHPError::InvalidDomain { message: "Domain cannot exceed 188 bits, found 192 bits".to_string() };
+        assert_eq!(Some(expected), BHP512::<Circuit>::from_bases(&domain, bases, random_base).err());
+        Circuit::reset();
+        Ok(())
+    }
+}
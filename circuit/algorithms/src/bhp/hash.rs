@@ -19,11 +19,59 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> Hash for BHP<
     type Output = Field<E>;
 
     /// Returns the BHP hash of the given input as a field element.
+    ///
+    /// This method halts if an iteration is given an invalid input size, which is suitable for the prover.
+    /// To surface the error to the caller instead (e.g. on the verifier side), use `BHP::try_hash`.
     fn hash(&self, input: &[Self::Input]) -> Self::Output {
         self.hash_uncompressed(input).to_x_coordinate()
     }
 }
 
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the BHP hash of the given input as a BHP digest.
+    ///
+    /// If the input is entirely constant, the digest is computed by the native BHP and injected as a constant
+    /// with no constraints, regardless of the mode of any other inputs in the surrounding circuit.
+    ///
+    /// The digest wraps the field element returned by `Hash::hash`, which is the only method named `hash`.
+    ///
+    /// The input may be any type that borrows as a slice of booleans (e.g. a `Vec`, a slice, or an array).
+    pub fn hash_digest(&self, input: impl AsRef<[Boolean<E>]>) -> BHPDigest<E> {
+        BHPDigest::new(self.hash_field(input))
+    }
+
+    /// Returns the BHP hash of the given input as a field element.
+    pub fn hash_field(&self, input: impl AsRef<[Boolean<E>]>) -> Field<E> {
+        Hash::hash(self, input.as_ref())
+    }
+
+    /// Returns the BHP hash of the given owned input as a field element, which equals `hash(&input)`.
+    ///
+    /// The given vector is reused as the scratch buffer of the hash, where the preimage of each iteration
+    /// is written after the input, which avoids allocating a separate buffer for callers who already own the input.
+    pub fn hash_owned(&self, mut input: Vec<Boolean<E>>) -> Field<E> {
+        match self.try_hash_iterations_in_scratch(None, &mut input) {
+            Ok(digest) => digest.to_x_coordinate(),
+            Err(error) => E::halt(error.to_string()),
+        }
+    }
+
+    /// Returns the BHP hash of the given input as a field element,
+    /// or a `BHPError` if an iteration is given an invalid input size, instead of halting.
+    ///
+    /// This method never calls `E::halt` for an invalid input size, and should be used
+    /// wherever the input is untrusted, such as on the verifier side:
+    /// ```ignore
+    /// let digest = match bhp.try_hash(&input) {
+    ///     Ok(digest) => digest,
+    ///     Err(error) => bail!("Failed to hash the input: {error}"),
+    /// };
+    /// ```
+    pub fn try_hash(&self, input: &[Boolean<E>]) -> Result<Field<E>, BHPError> {
+        Ok(self.try_hash_uncompressed(input)?.to_x_coordinate())
+    }
+}
+
 #[cfg(all(test, console))]
 mod tests {
     use super::*;
@@ -46,7 +94,7 @@ mod tests {
 
         // Initialize BHP.
         let native = console::BHP::<<Circuit as Environment>::Network, NUM_WINDOWS, WINDOW_SIZE>::setup(DOMAIN)?;
-        let circuit = BHP::<Circuit, NUM_WINDOWS, WINDOW_SIZE>::new(Mode::Constant, native.clone());
+        let circuit = BHP::<Circuit, NUM_WINDOWS, WINDOW_SIZE>::from_native_bases(&native, Mode::Constant);
         // Determine the number of inputs.
         let num_input_bits = NUM_WINDOWS as usize * WINDOW_SIZE as usize * BHP_CHUNK_SIZE;
 
@@ -85,4 +133,615 @@ mod tests {
     fn test_hash_private() -> Result<()> {
         check_hash::<32, 48>(Mode::Private, 470, 0, 8774, 8776)
     }
+
+    /// Checks that hashing inputs with every bit set to the given value matches the native hash,
+    /// for inputs within one iteration, at the maximum iteration length, and spanning two iterations.
+    fn check_hash_uniform_input(bit: bool) -> Result<()> {
+        use console::Hash as H;
+
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+
+        for num_bits in [1, 3, 256, 521, 522, 1044] {
+            // Compute the expected hash.
+            let input = vec![bit; num_bits];
+            let expected = native.hash(&input)?;
+
+            for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input.clone());
+                let candidate = circuit.hash(&circuit_input);
+                assert_eq!(expected, candidate.eject_value(), "(bit = {bit}, num_bits = {num_bits}, mode = {mode})");
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_all_ones_input() -> Result<()> {
+        // Every chunk of the input sets `bit_0`, `bit_1`, `bit_0 & bit_1`, and negates its y-coordinate with `bit_2`.
+        check_hash_uniform_input(true)
+    }
+
+    #[test]
+    fn test_hash_all_zeros_input() -> Result<()> {
+        // Every chunk of the input selects the first lookup of its base, without negation.
+        check_hash_uniform_input(false)
+    }
+
+    #[test]
+    fn test_hash_constant_input_is_constraint_free() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native);
+
+        let mut rng = TestRng::default();
+
+        for num_bits in [0, 1, 256, 522, 1044] {
+            // Ensure hashing a constant input adds no constraints.
+            let input: Vec<Boolean<_>> =
+                Inject::new(Mode::Constant, (0..num_bits).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>());
+            let digest = Circuit::assert_constraint_free(|| circuit.hash_field(&input));
+            assert!(digest.is_constant());
+        }
+        Circuit::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_constant_delegates_to_native() -> Result<()> {
+        use console::Hash as H;
+
+        // Initialize BHP, and a BHP from the same generators without the native BHP, which hashes in the circuit.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+        let bases = native.bases().iter().map(|window| Inject::constant(window.clone())).collect();
+        let random_base = Inject::constant(native.random_base().to_vec());
+        let constrained = BHP512::<Circuit>::from_bases(DOMAIN, bases, random_base)?;
+        Circuit::reset();
+
+        let mut rng = TestRng::default();
+
+        for num_bits in [0, 1, 256, 522, 1044] {
+            // Sample a random input, and compute the expected hash.
+            let input = (0..num_bits).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+            let expected = native.hash(&input)?;
+            let constant_input: Vec<Boolean<_>> = Inject::new(Mode::Constant, input.clone());
+
+            // Ensure the constant input is hashed natively, and injected as a constant digest.
+            let candidate = Circuit::scope(format!("BHP constant {num_bits}"), || {
+                let candidate = circuit.hash_field(&constant_input);
+                assert_scope!(2, 0, 0, 0);
+                candidate
+            });
+            assert!(candidate.is_constant());
+            assert_eq!(expected, candidate.eject_value());
+
+            // Ensure every other entry point delegates the constant input to the native hash.
+            let context = BHPContext::new();
+            for candidate in [
+                circuit.try_hash(&constant_input)?,
+                circuit.hash_uncompressed(&constant_input).to_x_coordinate(),
+                circuit.hash_in_context(&context, &constant_input),
+                circuit.hash_with_scratch(&constant_input, &mut Vec::new()),
+            ] {
+                assert!(candidate.is_constant());
+                assert_eq!(expected, candidate.eject_value());
+            }
+
+            // Ensure the native hash matches the circuit hash, for both the constant and the private input.
+            assert_eq!(expected, constrained.hash_field(&constant_input).eject_value());
+            assert_eq!(expected, constrained.hash_native_bits(&input, Mode::Private).eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_max_iteration_length() -> Result<()> {
+        use console::Hash as H;
+
+        // Initialize BHP.
+        let native = console::BHP::<<Circuit as Environment>::Network, 32, 48>::setup(DOMAIN)?;
+        let circuit = BHP::<Circuit, 32, 48>::constant(native.clone());
+        // Determine the maximum number of input bits that fit in one iteration, which uses all 32 windows.
+        let num_input_bits = 32 * 48 * BHP_CHUNK_SIZE - <Circuit as Environment>::BaseField::size_in_data_bits();
+
+        let mut rng = TestRng::default();
+
+        // Note: A constant input is hashed natively, so no window is evaluated, though the context
+        // of the metrics allocates its 3 constants up front.
+        for (mode, num_constants, num_public, num_private, num_constraints) in
+            [(Mode::Constant, 5, 0, 0, 0), (Mode::Public, 461, 0, 7415, 7415), (Mode::Private, 461, 0, 7415, 7415)]
+        {
+            // Sample a random input.
+            let input = (0..num_input_bits).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+            // Compute the expected hash.
+            let expected = native.hash(&input)?;
+            // Prepare the circuit input.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+            Circuit::scope(format!("BHP {mode}"), || {
+                // Perform the hash operation.
+                let (candidate, metrics) = circuit.hash_with_metrics(&circuit_input);
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+                assert_eq!(expected, candidate.eject_value());
+                assert_eq!(if mode.is_constant() { 0 } else { 32 }, metrics.windows_used);
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_snapshot_diff() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native);
+
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Prepare two random inputs.
+            let first: Vec<Boolean<_>> = Inject::new(mode, (0..522).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>());
+            let second: Vec<Boolean<_>> = Inject::new(mode, (0..522).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>());
+
+            // Measure the cost of a single hash.
+            let snapshot = Circuit::snapshot();
+            circuit.hash_field(&first);
+            let single = snapshot.diff();
+
+            // Ensure the cost of two hashes is twice the cost of a single hash.
+            let snapshot = Circuit::snapshot();
+            circuit.hash_field(&first);
+            circuit.hash_field(&second);
+            assert_eq!(single + single, snapshot.diff(), "(mode = {mode})");
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_exports_satisfied_matrices() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native);
+
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Public, Mode::Private] {
+            // Hash a random input.
+            let input: Vec<Boolean<_>> = Inject::new(mode, (0..522).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>());
+            circuit.hash_field(&input);
+            let num_constraints = Circuit::num_constraints();
+
+            // Export the matrices of the hash, and ensure they are satisfied by the witness.
+            let mut matrices = Circuit::eject_assignment_and_reset().to_matrices();
+            assert_eq!(num_constraints as usize, matrices.num_constraints());
+            assert!(matrices.is_satisfied(), "(mode = {mode})");
+
+            // Ensure the matrices are not satisfied by a tampered witness.
+            let last = matrices.z().len() - 1;
+            matrices.z_mut()[last] += <Circuit as Environment>::BaseField::one();
+            assert!(!matrices.is_satisfied(), "(mode = {mode})");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_trips_constraint_limit() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native);
+
+        let mut rng = TestRng::default();
+        let input = (0..522).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+
+        // Ensure a hash within the limit succeeds, where the hash costs 895 constraints beyond the input.
+        let circuit_input: Vec<Boolean<_>> = Inject::new(Mode::Private, input.clone());
+        let limit = Circuit::num_constraints() + 895;
+        Circuit::set_constraint_limit(Some(limit));
+        circuit.hash_field(circuit_input);
+        assert_eq!(limit, Circuit::num_constraints());
+        Circuit::set_constraint_limit(None);
+        Circuit::reset();
+
+        // Ensure a hash beyond a low limit trips the guard.
+        let circuit_input: Vec<Boolean<_>> = Inject::new(Mode::Private, input);
+        let limit = Circuit::num_constraints() + 100;
+        Circuit::set_constraint_limit(Some(limit));
+        let result = std::panic::catch_unwind(|| circuit.hash_field(&circuit_input));
+        assert_eq!(Some(limit), Circuit::constraint_limit());
+        Circuit::set_constraint_limit(None);
+        Circuit::reset();
+
+        let error = result.unwrap_err();
+        let message = error.downcast_ref::<String>().expect("Expected a message");
+        assert!(message.contains(&format!("Surpassed the constraint limit of {limit}")), "{message}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_accepts_vec_slice_and_array() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native);
+
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Sample a random input.
+            let input = (0..256).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+            // Hash the input as a borrowed vector, a slice, an array, and an owned vector.
+            let array: [Boolean<_>; 256] = circuit_input.clone().try_into().unwrap();
+            let expected = circuit.hash_digest(&circuit_input).eject_value();
+            assert_eq!(expected, circuit.hash_digest(&circuit_input[..]).eject_value());
+            assert_eq!(expected, circuit.hash_digest(&array).eject_value());
+            assert_eq!(expected, circuit.hash_digest(array).eject_value());
+            assert_eq!(expected, circuit.hash_digest(circuit_input).eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_hash() -> Result<()> {
+        use console::Hash as H;
+
+        // Initialize BHP.
+        let native = console::BHP::<<Circuit as Environment>::Network, 32, 48>::setup(DOMAIN)?;
+        let circuit = BHP::<Circuit, 32, 48>::new(Mode::Constant, native.clone());
+
+        let mut rng = TestRng::default();
+
+        // Check empty, single-iteration, and multi-iteration inputs.
+        for num_input_bits in [0, 1, 100, 4608, 10000] {
+            for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                // Sample a random input.
+                let input = (0..num_input_bits).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+                // Compute the expected hash.
+                let expected = native.hash(&input)?;
+                // Prepare the circuit input.
+                let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+                // Ensure the verifier-safe variant matches the native hash.
+                let candidate = circuit.try_hash(&circuit_input)?;
+                assert_eq!(expected, candidate.eject_value());
+                // Ensure the prover variant matches the verifier-safe variant.
+                assert_eq!(expected, circuit.hash(&circuit_input).eject_value());
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_with_native_bases() -> Result<()> {
+        use console::Hash as H;
+
+        // Initialize BHP.
+        let native = console::BHP::<<Circuit as Environment>::Network, 32, 48>::setup(DOMAIN)?;
+        // Initialize one circuit with the explicitly-shared native bases, and one with the injected primitive.
+        let shared = BHP::<Circuit, 32, 48>::from_native_bases(&native, Mode::Constant);
+        let injected = BHP::<Circuit, 32, 48>::constant(native.clone());
+
+        let mut rng = TestRng::default();
+
+        for num_input_bits in [0, 1, 100, 4608, 10000] {
+            for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                // Sample a random input.
+                let input = (0..num_input_bits).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+                // Compute the expected hash.
+                let expected = native.hash(&input)?;
+                // Prepare the circuit input.
+                let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+                // Ensure both circuits produce identical outputs, matching the native hash.
+                assert_eq!(expected, shared.hash(&circuit_input).eject_value());
+                assert_eq!(expected, injected.hash(&circuit_input).eject_value());
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_counts_after_reset() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP::<<Circuit as Environment>::Network, 32, 48>::setup(DOMAIN)?;
+
+        let mut rng = TestRng::default();
+
+        // Synthesizes the hash circuit from a fresh instance, and returns the resulting counts.
+        let mut synthesize = || {
+            let circuit = BHP::<Circuit, 32, 48>::new(Mode::Constant, native.clone());
+            let input = (0..4608).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+            let circuit_input: Vec<Boolean<_>> = Inject::new(Mode::Private, input);
+            let _candidate = circuit.hash(&circuit_input);
+            Circuit::count()
+        };
+
+        // Synthesize the hash circuit once.
+        let first = synthesize();
+        // Ensure the reset zeroes the counts (excluding the public `1` variable).
+        Circuit::reset();
+        assert_eq!(
+            (0, 1, 0, 0),
+            (Circuit::num_constants(), Circuit::num_public(), Circuit::num_private(), Circuit::num_constraints())
+        );
+        // Ensure a subsequent synthesis produces the same counts as the first.
+        let second = synthesize();
+        assert_eq!(first, second);
+        Circuit::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_constant_input_in_witnessed_circuit() -> Result<()> {
+        use console::Hash as H;
+
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+
+        let mut rng = TestRng::default();
+
+        // Sample a witnessed input and a constant input.
+        let witnessed_input = (0..522).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+        let constant_input = (0..522).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+        let expected = native.hash(&constant_input)?;
+        let witnessed_input: Vec<Boolean<_>> = Inject::new(Mode::Private, witnessed_input);
+        let constant_input: Vec<Boolean<_>> = Inject::new(Mode::Constant, constant_input);
+
+        Circuit::scope("BHP witnessed and constant", || {
+            // Perform the witnessed hash.
+            let witnessed = circuit.hash_digest(&witnessed_input);
+            assert!(!witnessed.as_field().is_constant());
+            let (num_public, num_private, num_constraints) =
+                (Circuit::num_public_in_scope(), Circuit::num_private_in_scope(), Circuit::num_constraints_in_scope());
+            assert!(num_constraints > 0);
+
+            // Perform the constant-input hash in the same scope.
+            let candidate = circuit.hash_digest(&constant_input);
+            assert!(candidate.as_field().is_constant());
+            assert_eq!(expected, candidate.eject_value());
+
+            // Ensure the constant-input hash adds no variables or constraints.
+            assert_eq!(num_public, Circuit::num_public_in_scope());
+            assert_eq!(num_private, Circuit::num_private_in_scope());
+            assert_eq!(num_constraints, Circuit::num_constraints_in_scope());
+        });
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_folds_constant_false_windows() -> Result<()> {
+        use console::Hash as H;
+
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+        Circuit::reset();
+
+        let mut rng = TestRng::default();
+
+        // BHP512 has 6 windows of 129 bits, so the last 129 bits of a 522-bit input span the last window.
+        let mut input = (0..393).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+        input.resize(522, false);
+        let expected = native.hash(&input)?;
+
+        // Hash the input with the last window as witnessed bits, and as constant `false` bits.
+        let witnessed_input: Vec<Boolean<_>> = Inject::new(Mode::Private, input.clone());
+        let mut folded_input: Vec<Boolean<_>> = Inject::new(Mode::Private, input[..393].to_vec());
+        folded_input.extend(Vec::<Boolean<_>>::constant(input[393..].to_vec()));
+        Circuit::reset();
+
+        let witnessed = Circuit::scope("BHP witnessed window", || {
+            let candidate = circuit.hash(&witnessed_input);
+            assert_scope!(409, 0, 895, 895);
+            candidate
+        });
+        let folded = Circuit::scope("BHP folded window", || {
+            let candidate = circuit.hash(&folded_input);
+            // The constant window costs no constraints, and is folded with the constant domain window.
+            assert_scope!(582, 0, 675, 675);
+            candidate
+        });
+
+        // Ensure both match the native hash.
+        assert_eq!(expected, witnessed.eject_value());
+        assert_eq!(expected, folded.eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_native_bits() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native);
+        Circuit::reset();
+
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Sample a random input.
+            let input = (0..522).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+
+            // Compute the hash by manually injecting the input.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input.clone());
+            let expected = circuit.hash_field(&circuit_input);
+            let expected_counts = Circuit::count();
+            Circuit::reset();
+
+            // Ensure the convenience method computes the same hash, with the same counts.
+            let candidate = circuit.hash_native_bits(&input, mode);
+            assert_eq!(expected.eject_value(), candidate.eject_value());
+            assert_eq!(expected.eject_mode(), candidate.eject_mode());
+            assert_eq!(expected_counts, Circuit::count());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_has_no_collisions_on_distinct_inputs() -> Result<()> {
+        use std::collections::HashSet;
+
+        // Retrieve the batch size, which is configurable via the `BHP_COLLISION_BATCH_SIZE` environment variable.
+        let batch_size = match std::env::var("BHP_COLLISION_BATCH_SIZE") {
+            Ok(batch_size) => batch_size.parse::<usize>()?,
+            Err(_) => 100,
+        };
+
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native);
+
+        let mut rng = TestRng::default();
+
+        // Sample a batch of distinct random inputs, including inputs that differ only in their last bit,
+        // which would collide if the last window was dropped.
+        let mut inputs = HashSet::with_capacity(batch_size);
+        while inputs.len() < batch_size {
+            let mut input = (0..522).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+            inputs.insert(input.clone());
+            input[521] = !input[521];
+            inputs.insert(input);
+        }
+
+        // Ensure no two digests collide.
+        let mut digests = HashSet::with_capacity(inputs.len());
+        for input in &inputs {
+            let circuit_input: Vec<Boolean<_>> = Inject::new(Mode::Private, input.clone());
+            assert!(digests.insert(circuit.hash_field(&circuit_input).eject_value()), "Found a collision");
+            Circuit::reset();
+        }
+        assert_eq!(inputs.len(), digests.len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_across_random_setup_seeds() -> Result<()> {
+        use console::Hash as H;
+
+        // Retrieve the number of seeds and inputs per seed, which are configurable via the
+        // `BHP_SEED_FUZZ_NUM_SEEDS` and `BHP_SEED_FUZZ_NUM_INPUTS` environment variables.
+        let num_seeds = match std::env::var("BHP_SEED_FUZZ_NUM_SEEDS") {
+            Ok(num_seeds) => num_seeds.parse::<usize>()?,
+            Err(_) => 4,
+        };
+        let num_inputs = match std::env::var("BHP_SEED_FUZZ_NUM_INPUTS") {
+            Ok(num_inputs) => num_inputs.parse::<usize>()?,
+            Err(_) => 3,
+        };
+
+        // Note: The seed of the RNG is printed, so a failing (seed, input) combination can be reproduced.
+        let mut rng = TestRng::default();
+
+        for _ in 0..num_seeds {
+            // Sample a random domain, which is at most 23 bytes (i.e. within the 188-bit domain limit).
+            let seed = format!("BHPSeed{:016x}", u64::rand(&mut rng));
+
+            // Initialize BHP with the random domain, which derives a new generator set.
+            let native = console::BHP512::<<Circuit as Environment>::Network>::setup(&seed)?;
+            let circuit = BHP512::<Circuit>::constant(native.clone());
+
+            for _ in 0..num_inputs {
+                // Sample a random input of up to two iterations.
+                let num_bits = u16::rand(&mut rng) as usize % 1045;
+                let input = (0..num_bits).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+                let expected = native.hash(&input)?;
+
+                for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                    let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input.clone());
+                    let candidate = circuit.hash_field(&circuit_input);
+                    assert_eq!(
+                        expected,
+                        candidate.eject_value(),
+                        "(seed = {seed}, num_bits = {num_bits}, mode = {mode})"
+                    );
+                    assert!(Circuit::is_satisfied(), "(seed = {seed}, num_bits = {num_bits}, mode = {mode})");
+                    Circuit::reset();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_owned_matches_hash() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native);
+        Circuit::reset();
+
+        let mut rng = TestRng::default();
+
+        // Ensure the owned and borrowed paths agree, including for inputs that require padding (e.g. 257 bits)
+        // and for inputs that span multiple iterations (e.g. 600 bits).
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for num_bits in [0, 1, 256, 257, 258, 522, 600] {
+                let input = (0..num_bits).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+                let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+                let expected = Circuit::scope("borrowed", || {
+                    let expected = circuit.hash_field(&circuit_input);
+                    (expected, Circuit::count_in_scope())
+                });
+                let candidate = Circuit::scope("owned", || {
+                    let candidate = circuit.hash_owned(circuit_input.clone());
+                    (candidate, Circuit::count_in_scope())
+                });
+                assert_eq!(
+                    expected.0.eject_value(),
+                    candidate.0.eject_value(),
+                    "(mode = {mode}, num_bits = {num_bits})"
+                );
+                assert_eq!(expected.1, candidate.1, "(mode = {mode}, num_bits = {num_bits})");
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_regression() -> Result<()> {
+        use console::ToBytes;
+
+        /// The pinned digest of the fixed input, as a big-endian hex string.
+        const EXPECTED: &str = "05fe25ad4933fbfcf6ebc3f1f7cd5488eb9180eb1b7bcdd4cffd97bac1c33702";
+
+        // Initialize BHP.
+        let native = console::BHP256::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP256::<Circuit>::constant(native);
+
+        // Hash a fixed input, with every third bit set.
+        let input = (0..261).map(|i| i % 3 == 0).collect::<Vec<bool>>();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input.clone());
+            let candidate = circuit.hash_field(&circuit_input).eject_value();
+            let candidate = candidate.to_bytes_le()?.iter().rev().map(|byte| format!("{byte:02x}")).collect::<String>();
+
+            // Ensure the digest matches the pinned digest for the `"BHPCircuit0"` generators.
+            // Note: If this test fails, the generator derivation of `setup` has changed, which is backward-incompatible.
+            assert_eq!(EXPECTED, candidate);
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
 }
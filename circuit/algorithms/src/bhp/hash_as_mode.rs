@@ -0,0 +1,125 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the BHP hash of the given input as a field element, witnessed in the given output mode.
+    ///
+    /// The digest is rewitnessed as a new variable in `output_mode`, and is enforced to equal the computed digest,
+    /// which costs 1 constraint. If `output_mode` is constant, the digest is returned as is, which requires
+    /// the computed digest to be constant (i.e. the input is constant), as a witnessed digest cannot be a constant.
+    pub fn hash_as_mode(&self, input: &[Boolean<E>], output_mode: Mode) -> Field<E> {
+        // Compute the digest.
+        let digest = self.hash_field(input);
+
+        match output_mode.is_constant() {
+            // Ensure the digest is constant.
+            true => match digest.is_constant() {
+                true => digest,
+                false => E::halt("Cannot output a BHP digest as a constant, as the digest is not constant"),
+            },
+            false => {
+                // Rewitness the digest in the given output mode.
+                let output = Field::new(output_mode, digest.eject_value());
+                // Ensure the output is equal to the digest.
+                E::assert_eq(&output, &digest); // 1 constraint
+                output
+            }
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+    const DOMAIN: &str = "BHPCircuit0";
+
+    fn check_hash_as_mode(
+        mode: Mode,
+        output_mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) -> Result<()> {
+        use console::Hash as H;
+
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+        Circuit::reset();
+
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random input.
+            let input = (0..512).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+            // Compute the expected hash.
+            let expected = native.hash(&input)?;
+            // Prepare the circuit input.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+            Circuit::scope(format!("BHP {mode} {output_mode} {i}"), || {
+                // Perform the hash operation.
+                let candidate = circuit.hash_as_mode(&circuit_input, output_mode);
+                assert_eq!(expected, candidate.eject_value());
+                assert_eq!(output_mode, candidate.eject_mode());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_as_mode_constant() -> Result<()> {
+        check_hash_as_mode(Mode::Constant, Mode::Constant, 2, 0, 0, 0)?;
+        check_hash_as_mode(Mode::Constant, Mode::Public, 2, 1, 0, 1)?;
+        check_hash_as_mode(Mode::Constant, Mode::Private, 2, 0, 1, 1)
+    }
+
+    #[test]
+    fn test_hash_as_mode_public() -> Result<()> {
+        check_hash_as_mode(Mode::Public, Mode::Public, 409, 1, 880, 881)?;
+        check_hash_as_mode(Mode::Public, Mode::Private, 409, 0, 881, 881)
+    }
+
+    #[test]
+    fn test_hash_as_mode_private() -> Result<()> {
+        check_hash_as_mode(Mode::Private, Mode::Public, 409, 1, 880, 881)?;
+        check_hash_as_mode(Mode::Private, Mode::Private, 409, 0, 881, 881)
+    }
+
+    #[test]
+    fn test_hash_as_mode_halts_on_witnessed_constant_output() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native);
+
+        // Ensure a witnessed digest cannot be output as a constant.
+        let input: Vec<Boolean<_>> = Inject::new(Mode::Private, vec![true; 512]);
+        let result = std::panic::catch_unwind(|| circuit.hash_as_mode(&input, Mode::Constant));
+        assert!(result.is_err());
+        Circuit::reset();
+        Ok(())
+    }
+}
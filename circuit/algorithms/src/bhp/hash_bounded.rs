@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the BHP hash of the given input as a field element, and halts if the input exceeds `max_bits`
+    /// (e.g. a protocol-specific bound for a fixed-format message).
+    ///
+    /// The input length is fixed at synthesis time, so the bound is checked when the circuit is built,
+    /// and adds no constraints.
+    pub fn hash_bounded(&self, input: &[Boolean<E>], max_bits: usize) -> Field<E> {
+        // Ensure the input does not exceed the given bound.
+        if input.len() > max_bits {
+            E::halt(BHPError::InputTooLong { num_bits: input.len(), max_bits }.to_string())
+        }
+        self.hash_field(input)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "BHPCircuit0";
+    const MAX_BITS: usize = 300;
+
+    #[test]
+    fn test_hash_bounded() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native);
+        Circuit::reset();
+
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for num_bits in [0, 1, MAX_BITS - 1, MAX_BITS] {
+                // Sample a random input within the bound.
+                let input: Vec<Boolean<_>> =
+                    Inject::new(mode, (0..num_bits).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>());
+                // Ensure the bounded hash matches the hash.
+                let expected = circuit.hash_field(&input);
+                let candidate = circuit.hash_bounded(&input, MAX_BITS);
+                assert_eq!(expected.eject_value(), candidate.eject_value());
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_bounded_halts_above_bound() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native);
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Ensure an input one bit above the bound halts, even though the hasher accepts it.
+            let input: Vec<Boolean<_>> = Inject::new(mode, vec![true; MAX_BITS + 1]);
+            let _digest = circuit.hash_field(&input);
+            let result = std::panic::catch_unwind(|| circuit.hash_bounded(&input, MAX_BITS));
+            assert!(result.is_err());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+}
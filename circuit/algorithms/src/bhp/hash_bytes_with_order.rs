@@ -0,0 +1,130 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the BHP hash of the given bytes as a field element, where the bits within each byte
+    /// are ordered by the given bit order, for interoperability with external systems.
+    ///
+    /// The bytes themselves are always hashed in the given order. Reordering the bits is free.
+    pub fn hash_bytes_with_order(&self, bytes: &[U8<E>], bit_order: BitOrder) -> Field<E> {
+        // Initialize a vector for the hash preimage.
+        let mut preimage = Vec::with_capacity(bytes.len() * 8);
+        for byte in bytes {
+            match bit_order {
+                BitOrder::Lsb0 => byte.write_bits_le(&mut preimage),
+                BitOrder::Msb0 => byte.write_bits_be(&mut preimage),
+            }
+        }
+        // Hash the preimage.
+        self.hash_field(&preimage)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+    const DOMAIN: &str = "BHPCircuit0";
+    const NUM_BYTES: usize = 64;
+
+    /// Returns the native BHP hash of the given bytes, with the bits within each byte in the given order.
+    fn native_hash_bytes_with_order(
+        native: &console::BHP512<<Circuit as Environment>::Network>,
+        bytes: &[u8],
+        bit_order: BitOrder,
+    ) -> Result<console::Field<<Circuit as Environment>::Network>> {
+        use console::{Hash as H, ToBits as TB};
+
+        let preimage = match bit_order {
+            BitOrder::Lsb0 => bytes.iter().flat_map(|byte| byte.to_bits_le()).collect::<Vec<_>>(),
+            BitOrder::Msb0 => bytes.iter().flat_map(|byte| byte.to_bits_be()).collect::<Vec<_>>(),
+        };
+        native.hash(&preimage)
+    }
+
+    fn check_hash_bytes_with_order(
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample random bytes.
+            let bytes = (0..NUM_BYTES).map(|_| u8::rand(&mut rng)).collect::<Vec<u8>>();
+            // Prepare the circuit bytes.
+            let circuit_bytes = bytes.iter().map(|byte| U8::new(mode, console::U8::new(*byte))).collect::<Vec<_>>();
+
+            let mut digests = Vec::with_capacity(2);
+            for bit_order in [BitOrder::Lsb0, BitOrder::Msb0] {
+                // Compute the expected digest.
+                let expected = native_hash_bytes_with_order(&native, &bytes, bit_order)?;
+
+                Circuit::scope(format!("BHP {mode} {bit_order:?} {i}"), || {
+                    let candidate = circuit.hash_bytes_with_order(&circuit_bytes, bit_order);
+                    assert_scope!(num_constants, num_public, num_private, num_constraints);
+                    assert_eq!(expected, candidate.eject_value());
+                });
+                digests.push(expected);
+            }
+            // Ensure the two bit orders produce different digests.
+            assert_ne!(digests[0], digests[1]);
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_bytes_with_order_constant() -> Result<()> {
+        check_hash_bytes_with_order(Mode::Constant, 2, 0, 0, 0)
+    }
+
+    #[test]
+    fn test_hash_bytes_with_order_public() -> Result<()> {
+        check_hash_bytes_with_order(Mode::Public, 409, 0, 880, 880)
+    }
+
+    #[test]
+    fn test_hash_bytes_with_order_private() -> Result<()> {
+        check_hash_bytes_with_order(Mode::Private, 409, 0, 880, 880)
+    }
+
+    #[test]
+    fn test_bit_order_msb0_reverses_each_byte() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+
+        // Ensure hashing with `Msb0` equals hashing the bit-reversed bytes with `Lsb0`.
+        let bytes = [0x01u8, 0x80, 0x0f, 0xa5];
+        let reversed = bytes.iter().map(|byte| byte.reverse_bits()).collect::<Vec<_>>();
+        assert_eq!(
+            native_hash_bytes_with_order(&native, &bytes, BitOrder::Msb0)?,
+            native_hash_bytes_with_order(&native, &reversed, BitOrder::Lsb0)?
+        );
+        Ok(())
+    }
+}
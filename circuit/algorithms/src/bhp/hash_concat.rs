@@ -0,0 +1,81 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the BHP hash of the concatenation of the given parts, as a field element.
+    ///
+    /// This equals `hash_field` of the joined parts. The parts are written once into the scratch buffer
+    /// of the hash, which also holds the preimage of each iteration, so the caller does not join them first.
+    pub fn hash_concat(&self, parts: &[&[Boolean<E>]]) -> Field<E> {
+        // Write the parts into the scratch buffer, with the exact capacity of the input.
+        let mut scratch = Vec::with_capacity(parts.iter().map(|part| part.len()).sum());
+        parts.iter().for_each(|part| scratch.extend_from_slice(part));
+        // Hash the input, writing the preimage of each iteration after it.
+        match self.try_hash_iterations_in_scratch(None, &mut scratch) {
+            Ok(digest) => digest.to_x_coordinate(),
+            Err(error) => E::halt(error.to_string()),
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "BHPCircuit0";
+
+    #[test]
+    fn test_hash_concat() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native);
+        Circuit::reset();
+
+        let mut rng = TestRng::default();
+
+        // Check parts that are empty, within one iteration, and span iteration boundaries.
+        for lengths in [vec![], vec![0, 0], vec![1, 2, 3], vec![256, 0, 256], vec![100, 521, 1, 300]] {
+            for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                // Sample the random parts.
+                let parts = lengths
+                    .iter()
+                    .map(|num_bits| {
+                        let part: Vec<Boolean<_>> =
+                            Inject::new(mode, (0..*num_bits).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>());
+                        part
+                    })
+                    .collect::<Vec<_>>();
+
+                // Ensure the hash of the parts matches the hash of the manually-joined parts, with the same counts.
+                let (expected, expected_count) =
+                    Circuit::scope("BHP joined", || (circuit.hash_field(parts.concat()), Circuit::count_in_scope()));
+                let (candidate, candidate_count) = Circuit::scope("BHP parts", || {
+                    let parts = parts.iter().map(|part| part.as_slice()).collect::<Vec<_>>();
+                    (circuit.hash_concat(&parts), Circuit::count_in_scope())
+                });
+                assert_eq!(expected.eject_value(), candidate.eject_value(), "(mode = {mode}, lengths = {lengths:?})");
+                assert_eq!(expected_count, candidate_count, "(mode = {mode}, lengths = {lengths:?})");
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+        Ok(())
+    }
+}
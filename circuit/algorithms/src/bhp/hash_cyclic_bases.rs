@@ -0,0 +1,138 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[cfg(console)]
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the BHP hash of the given input as a field element, in a single iteration over
+    /// `[ 0...0 || DOMAIN || LENGTH(INPUT) || INPUT ]` that reuses the bases cyclically with an offset per cycle,
+    /// instead of chaining iterations when the preimage exceeds `NUM_WINDOWS` windows.
+    ///
+    /// This opt-in mode is intended for parameter-constrained deployments. As windows in different cycles
+    /// reuse the bases at a known offset, it does *not* provide the collision resistance of `hash`.
+    pub fn hash_cyclic_bases(&self, input: &[Boolean<E>]) -> Field<E> {
+        // Construct the preimage as: [ 0...0 || DOMAIN || LENGTH(INPUT) || INPUT ].
+        let mut preimage = Vec::with_capacity(self.domain.len() + 64 + input.len());
+        preimage.extend(self.domain.clone());
+        U64::constant(console::U64::new(input.len() as u64)).write_bits_le(&mut preimage);
+        preimage.extend_from_slice(input);
+        // Hash the preimage with the cyclic bases.
+        self.hasher.hash_uncompressed_cyclic_bases(&preimage).to_x_coordinate()
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+    const DOMAIN: &str = "BHPCircuit0";
+
+    type NativeField = console::Field<<Circuit as Environment>::Network>;
+
+    /// Returns the native BHP hash of the given input, hashing each cycle of the preimage with the console
+    /// BHP hasher, scaled by the offset of the cycle.
+    fn native_hash_cyclic_bases(
+        native: &console::BHP256<<Circuit as Environment>::Network>,
+        input: &[bool],
+    ) -> Result<NativeField> {
+        use console::{HashUncompressed as _, ToBits as TB};
+
+        type NativeHasher = console::bhp::hasher::BHPHasher<<Circuit as Environment>::Network, 3, 57>;
+
+        // Construct the preimage.
+        let mut preimage = native.domain().to_vec();
+        preimage.extend(console::U64::<<Circuit as Environment>::Network>::new(input.len() as u64).to_bits_le());
+        preimage.extend_from_slice(input);
+
+        // Sum the hash of each cycle, scaled by the offset `c + 1` of the cycle.
+        let hasher = NativeHasher::setup(DOMAIN)?;
+        let mut digest = console::Group::zero();
+        for (cycle, bits) in preimage.chunks(3 * 57 * BHP_CHUNK_SIZE).enumerate() {
+            let offset = console::Scalar::from_field_lossy(&console::Field::from_u64(cycle as u64 + 1));
+            digest += hasher.hash_uncompressed(bits)? * offset;
+        }
+        Ok(digest.to_x_coordinate())
+    }
+
+    fn check_hash_cyclic_bases(
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP256::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP256::<Circuit>::constant(native.clone());
+
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random input, which exceeds the number of windows in the bases.
+            let input = (0..600).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+            // Compute the expected digest.
+            let expected = native_hash_cyclic_bases(&native, &input)?;
+            // Prepare the circuit input.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+            Circuit::scope(format!("BHP {mode} {i}"), || {
+                let candidate = circuit.hash_cyclic_bases(&circuit_input);
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+                assert_eq!(expected, candidate.eject_value());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_cyclic_bases_constant() -> Result<()> {
+        check_hash_cyclic_bases(Mode::Constant, 1473, 0, 0, 0)
+    }
+
+    #[test]
+    fn test_hash_cyclic_bases_public() -> Result<()> {
+        check_hash_cyclic_bases(Mode::Public, 662, 0, 1031, 1031)
+    }
+
+    #[test]
+    fn test_hash_cyclic_bases_private() -> Result<()> {
+        check_hash_cyclic_bases(Mode::Private, 662, 0, 1031, 1031)
+    }
+
+    #[test]
+    fn test_hash_cyclic_bases_matches_hash_within_one_cycle() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP256::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP256::<Circuit>::constant(native);
+
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Sample a random input, which fits in a single cycle of the bases.
+            let input: Vec<Boolean<_>> = Inject::new(mode, (0..261).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>());
+            // Ensure the digest matches the digest of `hash`.
+            assert_eq!(circuit.hash_field(&input).eject_value(), circuit.hash_cyclic_bases(&input).eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,146 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_circuit_types::environment::R1CS;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the exact number of constraints that `hash` would add for the given input,
+    /// without adding them to the environment.
+    ///
+    /// Unlike an analytic estimate, this runs the hash, so constant-folding of constant (or mixed-mode)
+    /// inputs is accounted for precisely. The current circuit is ejected, the hash is synthesized
+    /// in a fresh circuit and counted, and the original circuit is injected back, so the environment
+    /// is unchanged afterwards (even if the hash halts). The digest is discarded.
+    pub fn hash_dry_run(&self, input: &[Boolean<E>]) -> usize {
+        // Copy the modes and values of the input, as its variables belong to the current circuit.
+        let input = input.iter().map(|bit| (bit.eject_mode(), bit.eject_value())).collect::<Vec<_>>();
+        Self::dry_run(|| {
+            // Inject a fresh copy of the input into the fresh circuit.
+            let input = input.into_iter().map(|(mode, bit)| Boolean::new(mode, bit)).collect::<Vec<_>>();
+            // Synthesize the hash, and count its constraints.
+            let num_constraints = E::num_constraints();
+            let _digest = self.hash(&input);
+            (E::num_constraints() - num_constraints) as usize
+        })
+    }
+
+    /// Returns the output of the given function, evaluated in a fresh circuit, which is discarded afterwards.
+    ///
+    /// The current circuit is set aside, and restored by a guard when the function returns or unwinds.
+    fn dry_run<T>(f: impl FnOnce() -> T) -> T {
+        /// Restores the set-aside circuit into the environment when dropped.
+        struct Restore<E: Environment>(Option<R1CS<E::BaseField>>);
+
+        impl<E: Environment> Drop for Restore<E> {
+            fn drop(&mut self) {
+                if let Some(r1cs) = self.0.take() {
+                    // Discard the throwaway circuit, and restore the original circuit.
+                    E::reset();
+                    E::inject_r1cs(r1cs);
+                }
+            }
+        }
+
+        // Set aside the current circuit, until the guard is dropped.
+        let _restore = Restore::<E>(Some(E::eject_r1cs_and_reset()));
+        f()
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "BHPCircuit0";
+
+    fn check_hash_dry_run(
+        modes: &[Mode],
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native);
+        Circuit::reset();
+
+        let mut rng = TestRng::default();
+
+        // Sample a random input, cycling through the given modes.
+        let input =
+            (0..512).map(|i| Boolean::new(modes[i % modes.len()], bool::rand(&mut rng))).collect::<Vec<Boolean<_>>>();
+        let count_before = Circuit::count();
+
+        // Perform the dry run, and ensure the environment is unchanged.
+        let candidate = circuit.hash_dry_run(&input);
+        assert_eq!(count_before, Circuit::count());
+        assert!(Circuit::is_satisfied());
+
+        Circuit::scope("BHP dry run", || {
+            // Perform the hash operation.
+            let _digest = circuit.hash(&input);
+            // Ensure the dry run count matches the actual count.
+            assert_eq!(candidate as u64, Circuit::num_constraints_in_scope());
+            assert_scope!(num_constants, num_public, num_private, num_constraints);
+        });
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_dry_run_constant() -> Result<()> {
+        check_hash_dry_run(&[Mode::Constant], 2, 0, 0, 0)
+    }
+
+    #[test]
+    fn test_hash_dry_run_private() -> Result<()> {
+        check_hash_dry_run(&[Mode::Private], 409, 0, 880, 880)
+    }
+
+    #[test]
+    fn test_hash_dry_run_mixed() -> Result<()> {
+        check_hash_dry_run(&[Mode::Constant, Mode::Public, Mode::Private], 409, 0, 709, 709)
+    }
+
+    #[test]
+    fn test_hash_dry_run_restores_on_halt() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a random input.
+        let input: Vec<Boolean<Circuit>> =
+            Inject::new(Mode::Private, (0..512).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>());
+        let count_before = Circuit::count();
+
+        // Ensure a dry run that halts restores the original circuit.
+        let result = std::panic::catch_unwind(|| {
+            BHP512::<Circuit>::dry_run(|| {
+                let _ = &input[0] & &input[1];
+                Circuit::halt::<_, ()>("Halted during the dry run")
+            })
+        });
+        assert!(result.is_err());
+        assert_eq!(count_before, Circuit::count());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the BHP hash of the given input as a field element, and enforces the digest point
+    /// is in the prime-order subgroup (e.g. for protocols sensitive to small-subgroup points).
+    ///
+    /// By construction, the digest is a sum of subgroup bases, so this check is redundant for an honest hasher,
+    /// and serves as a belt-and-suspenders check. The point is enforced to be in the subgroup via `enforce_in_group`,
+    /// which witnesses the digest divided by the cofactor, enforces it is on the curve, and enforces that
+    /// the doubled double equals the digest. For a non-constant input, this costs 13 constraints
+    /// (and 12 private variables) beyond `hash`.
+    pub fn hash_in_subgroup(&self, input: &[Boolean<E>]) -> Field<E> {
+        // Compute the digest point.
+        let digest = self.hash_uncompressed(input);
+        // Ensure the digest point is in the prime-order subgroup.
+        digest.enforce_in_group();
+        digest.to_x_coordinate()
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+    const DOMAIN: &str = "BHPCircuit0";
+
+    fn check_hash_in_subgroup(
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) -> Result<()> {
+        use console::Hash as H;
+
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+        Circuit::reset();
+
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random input.
+            let input = (0..512).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+            // Compute the expected hash.
+            let expected = native.hash(&input)?;
+            // Prepare the circuit input.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+            Circuit::scope(format!("BHP {mode} {i}"), || {
+                // Perform the hash operation.
+                let candidate = circuit.hash_in_subgroup(&circuit_input);
+                assert_eq!(expected, candidate.eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_in_subgroup_constant() -> Result<()> {
+        check_hash_in_subgroup(Mode::Constant, 10, 0, 0, 0)
+    }
+
+    #[test]
+    fn test_hash_in_subgroup_public() -> Result<()> {
+        check_hash_in_subgroup(Mode::Public, 413, 0, 892, 893)
+    }
+
+    #[test]
+    fn test_hash_in_subgroup_private() -> Result<()> {
+        check_hash_in_subgroup(Mode::Private, 413, 0, 892, 893)
+    }
+}
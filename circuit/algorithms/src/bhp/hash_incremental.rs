@@ -0,0 +1,134 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the points of each window of the single-iteration preimage `[ 0...0 || DOMAIN || LENGTH(INPUT) || INPUT ]`,
+    /// whose sum is the BHP hash of the given input. These points may be cached for `BHP::hash_incremental`.
+    ///
+    /// This method halts if the input does not fit in a single iteration.
+    pub fn window_points(&self, input: &[Boolean<E>]) -> Vec<Group<E>> {
+        let window_size_in_bits = WINDOW_SIZE as usize * BHP_CHUNK_SIZE;
+        self.single_iteration_preimage(input)
+            .chunks(window_size_in_bits)
+            .enumerate()
+            .map(|(index, window)| self.hasher.hash_window(index, window))
+            .collect()
+    }
+
+    /// Returns the BHP hash of an input whose window at `changed_window` is replaced with the given bits of
+    /// the preimage, reusing the cached points of the unchanged windows, along with the updated window points.
+    ///
+    /// This speeds up hashing many nearly-identical inputs, and is not a soundness mechanism: the cached points
+    /// are taken as given, so they must be the output of `BHP::window_points` for the same preimage. As the
+    /// input length is encoded in the first window, the update must preserve the length of the input.
+    pub fn hash_incremental(
+        &self,
+        prev_window_points: &[Group<E>],
+        changed_window: usize,
+        new_bits: &[Boolean<E>],
+    ) -> (Field<E>, Vec<Group<E>>) {
+        // Ensure the changed window is one of the cached windows.
+        if changed_window >= prev_window_points.len() {
+            E::halt(format!(
+                "The changed BHP window {changed_window} exceeds the {} cached windows",
+                prev_window_points.len()
+            ))
+        }
+
+        // Hash the changed window, and reuse the cached points of the unchanged windows.
+        let mut window_points = prev_window_points.to_vec();
+        window_points[changed_window] = self.hasher.hash_window(changed_window, new_bits);
+
+        // Sum the window points, and return the x-coordinate as the digest.
+        let digest = window_points.iter().fold(Group::zero(), |acc, point| acc + point);
+        (digest.to_x_coordinate(), window_points)
+    }
+
+    /// Returns the single-iteration preimage `[ 0...0 || DOMAIN || LENGTH(INPUT) || INPUT ]` of the given input.
+    ///
+    /// This method halts if the input does not fit in a single iteration.
+    fn single_iteration_preimage(&self, input: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        // Ensure the input is not empty, and fits in a single iteration.
+        match Self::max_input_bits_per_iteration() {
+            Ok(max_bits) if !input.is_empty() && input.len() <= max_bits => (),
+            Ok(max_bits) => E::halt(format!("Incremental BHP inputs must be 1 to {max_bits} bits")),
+            Err(error) => E::halt(error.to_string()),
+        }
+
+        // Initialize a vector for the hash preimage.
+        let mut preimage = Vec::with_capacity(self.domain.len() + 64 + input.len());
+        preimage.extend(self.domain.clone());
+        U64::constant(console::U64::new(input.len() as u64)).write_bits_le(&mut preimage);
+        preimage.extend_from_slice(input);
+        preimage
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+    const DOMAIN: &str = "BHPCircuit0";
+
+    #[test]
+    fn test_hash_incremental() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native);
+        Circuit::reset();
+
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for _ in 0..ITERATIONS {
+                // Sample a random input, with a partial last window.
+                let input = (0..500).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+                let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input.clone());
+
+                // Ensure the sum of the window points is the hash of the input.
+                let window_points = circuit.window_points(&circuit_input);
+                assert_eq!(6, window_points.len());
+                let digest = window_points.iter().fold(Group::zero(), |acc, point| acc + point).to_x_coordinate();
+                assert_eq!(circuit.hash_field(&circuit_input).eject_value(), digest.eject_value());
+
+                // Modify a random bit of the input, which lies in one window of the preimage.
+                let index = (u32::rand(&mut rng) % 500) as usize;
+                let mut modified = input;
+                modified[index] = !modified[index];
+                let circuit_modified: Vec<Boolean<_>> = Inject::new(mode, modified);
+
+                // Retrieve the bits of the changed window from the modified preimage.
+                let window_size_in_bits = 43 * BHP_CHUNK_SIZE;
+                let changed_window = (252 + index) / window_size_in_bits;
+                let preimage = circuit.single_iteration_preimage(&circuit_modified);
+                let new_bits = preimage.chunks(window_size_in_bits).nth(changed_window).unwrap();
+
+                // Ensure the incremental update equals a full rehash of the modified input.
+                let (candidate, updated_points) = circuit.hash_incremental(&window_points, changed_window, new_bits);
+                assert_eq!(circuit.hash_field(&circuit_modified).eject_value(), candidate.eject_value());
+                assert_eq!(circuit.window_points(&circuit_modified).eject_value(), updated_points.eject_value());
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+        Ok(())
+    }
+}
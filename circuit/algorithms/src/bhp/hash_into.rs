@@ -0,0 +1,116 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Computes the BHP hash of the given input, and enforces the pre-allocated output `out` equals the digest
+    /// (e.g. for output slots allocated upfront in a batch), which costs 1 constraint.
+    ///
+    /// The output keeps its variable, so its mode and position in the circuit are unchanged.
+    /// If `out` does not equal the digest, the circuit is unsatisfied (or, if both are constant, halts).
+    pub fn hash_into(&self, input: &[Boolean<E>], out: &mut Field<E>) {
+        // Compute the digest.
+        let digest = self.hash_field(input);
+        // Ensure the output is equal to the digest.
+        E::assert_eq(&*out, digest); // 1 constraint
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+    const DOMAIN: &str = "BHPCircuit0";
+
+    fn check_hash_into(
+        mode: Mode,
+        output_mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) -> Result<()> {
+        use console::Hash as H;
+
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+        Circuit::reset();
+
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random input.
+            let input = (0..512).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+            // Compute the expected hash.
+            let expected = native.hash(&input)?;
+            // Prepare the circuit input, and pre-allocate the output.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+            let mut out = Field::new(output_mode, expected);
+
+            Circuit::scope(format!("BHP {mode} {i}"), || {
+                // Perform the hash operation.
+                circuit.hash_into(&circuit_input, &mut out);
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            // Ensure the output equals the digest returned by `hash`.
+            assert_eq!(circuit.hash_field(&circuit_input).eject_value(), out.eject_value());
+            assert_eq!(output_mode, out.eject_mode());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_into_constant() -> Result<()> {
+        check_hash_into(Mode::Constant, Mode::Constant, 2, 0, 0, 0)?;
+        check_hash_into(Mode::Constant, Mode::Public, 2, 0, 0, 1)
+    }
+
+    #[test]
+    fn test_hash_into_public() -> Result<()> {
+        check_hash_into(Mode::Public, Mode::Public, 409, 0, 880, 881)
+    }
+
+    #[test]
+    fn test_hash_into_private() -> Result<()> {
+        check_hash_into(Mode::Private, Mode::Private, 409, 0, 880, 881)
+    }
+
+    #[test]
+    fn test_hash_into_rejects_incorrect_output() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native);
+        Circuit::reset();
+
+        let mut rng = TestRng::default();
+
+        // Pre-allocate an output that does not equal the digest.
+        let input: Vec<Boolean<_>> =
+            Inject::new(Mode::Private, (0..512).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>());
+        let mut out = Field::new(Mode::Public, circuit.hash_field(&input).eject_value() + console::Field::one());
+        circuit.hash_into(&input, &mut out);
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+        Ok(())
+    }
+}
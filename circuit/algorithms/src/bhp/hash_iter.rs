@@ -0,0 +1,83 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the BHP hash of the given input bits as a field element, which equals `hash_field` of the
+    /// collected bits. The bits are given as an iterator of references (e.g. a flattened view over bits
+    /// stored in fixed-size rows), and are collected once into the scratch buffer of the hash, which also
+    /// holds the preimage of each iteration, so the caller does not need to collect the bits first.
+    pub fn hash_iter<'a, I>(&self, input: I) -> Field<E>
+    where
+        I: IntoIterator<Item = &'a Boolean<E>>,
+        E: 'a,
+    {
+        // Collect the input bits into the scratch buffer.
+        let mut scratch = input.into_iter().cloned().collect::<Vec<_>>();
+        // Hash the input, writing the preimage of each iteration after it.
+        match self.try_hash_iterations_in_scratch(None, &mut scratch) {
+            Ok(digest) => digest.to_x_coordinate(),
+            Err(error) => E::halt(error.to_string()),
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "BHPCircuit0";
+
+    #[test]
+    fn test_hash_iter_matches_hash() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native);
+        Circuit::reset();
+
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Check an empty input, inputs within one iteration, and inputs spanning multiple iterations.
+            for num_rows in [0, 1, 13, 65, 131] {
+                // Store the bits compactly, in rows of 8 bits.
+                let rows = (0..num_rows)
+                    .map(|_| {
+                        let row: Vec<Boolean<_>> =
+                            Inject::new(mode, (0..8).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>());
+                        row
+                    })
+                    .collect::<Vec<_>>();
+                let flattened = rows.concat();
+
+                // Ensure the view hash matches the slice hash, with the same counts.
+                let (expected, expected_count) =
+                    Circuit::scope("BHP slice", || (circuit.hash_field(&flattened), Circuit::count_in_scope()));
+                let (candidate, candidate_count) = Circuit::scope("BHP view", || {
+                    (circuit.hash_iter(rows.iter().flatten()), Circuit::count_in_scope())
+                });
+                assert_eq!(expected.eject_value(), candidate.eject_value(), "(mode = {mode}, num_rows = {num_rows})");
+                assert_eq!(expected_count, candidate_count, "(mode = {mode}, num_rows = {num_rows})");
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+        Ok(())
+    }
+}
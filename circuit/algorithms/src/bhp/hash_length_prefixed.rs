@@ -0,0 +1,159 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the BHP hash of a variable-length string stored in a fixed-size buffer, as a field element.
+    ///
+    /// The preimage is `[ LENGTH || BYTES ]`, where `LENGTH` is the 32-bit string length.
+    /// This method enforces that `length` does not exceed the buffer size, and that every byte
+    /// beyond `length` is zero, so each string has exactly one valid buffer encoding.
+    pub fn hash_length_prefixed(&self, bytes: &[U8<E>], length: &U32<E>) -> Field<E> {
+        // Ensure the buffer size fits in a `u32`.
+        let num_bytes = match u32::try_from(bytes.len()) {
+            Ok(num_bytes) => num_bytes,
+            Err(_) => E::halt(format!("The buffer cannot exceed {} bytes, found {} bytes", u32::MAX, bytes.len())),
+        };
+
+        // Ensure the length does not exceed the buffer size.
+        E::assert(length.is_less_than_or_equal(&U32::constant(console::U32::new(num_bytes))));
+
+        // Initialize a vector for the hash preimage.
+        let mut preimage = Vec::with_capacity(32 + bytes.len() * 8);
+        length.write_bits_le(&mut preimage);
+
+        for (index, byte) in (0..num_bytes).zip_eq(bytes) {
+            // Determine if this byte is within the string.
+            let is_within_length = U32::constant(console::U32::new(index)).is_less_than(length);
+            // Ensure the byte is zero if it is beyond the string.
+            E::assert(is_within_length | byte.is_equal(&U8::zero()));
+            // Append the byte to the preimage.
+            byte.write_bits_le(&mut preimage);
+        }
+
+        // Hash the preimage.
+        self.hash_field(&preimage)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "BHPCircuit0";
+    const BUFFER_SIZE: usize = 16;
+
+    /// Returns the native hash of the given string, stored in a zero-padded buffer.
+    fn native_hash_length_prefixed(
+        native: &console::BHP512<<Circuit as Environment>::Network>,
+        string: &str,
+    ) -> Result<console::Field<<Circuit as Environment>::Network>> {
+        use console::{Hash as H, ToBits as TB};
+
+        let mut buffer = string.as_bytes().to_vec();
+        buffer.resize(BUFFER_SIZE, 0u8);
+
+        let mut preimage = (string.len() as u32).to_bits_le();
+        preimage.extend(buffer.to_bits_le());
+        native.hash(&preimage)
+    }
+
+    fn check_hash_length_prefixed(
+        mode: Mode,
+        string: &str,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+
+        // Compute the expected hash.
+        let expected = native_hash_length_prefixed(&native, string)?;
+
+        // Prepare the partially-filled circuit buffer.
+        let mut buffer = string.as_bytes().to_vec();
+        buffer.resize(BUFFER_SIZE, 0u8);
+        let bytes = buffer.into_iter().map(|byte| U8::new(mode, console::U8::new(byte))).collect::<Vec<_>>();
+        let length = U32::new(mode, console::U32::new(string.len() as u32));
+
+        Circuit::scope(format!("BHP {mode}"), || {
+            let candidate = circuit.hash_length_prefixed(&bytes, &length);
+            assert_scope!(num_constants, num_public, num_private, num_constraints);
+            assert_eq!(expected, candidate.eject_value());
+        });
+        Circuit::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_length_prefixed_constant() -> Result<()> {
+        check_hash_length_prefixed(Mode::Constant, "hello", 691, 0, 0, 0)
+    }
+
+    #[test]
+    fn test_hash_length_prefixed_public() -> Result<()> {
+        check_hash_length_prefixed(Mode::Public, "hello", 1621, 0, 893, 927)
+    }
+
+    #[test]
+    fn test_hash_length_prefixed_private() -> Result<()> {
+        check_hash_length_prefixed(Mode::Private, "hello", 1621, 0, 893, 927)
+    }
+
+    #[test]
+    fn test_hash_length_prefixed_rejects_nonzero_trailing_byte() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native);
+
+        // Prepare a buffer with a nonzero byte beyond the string length.
+        let mut buffer = b"hello".to_vec();
+        buffer.resize(BUFFER_SIZE, 0u8);
+        buffer[BUFFER_SIZE - 1] = 1u8;
+        let bytes = buffer.into_iter().map(|byte| U8::new(Mode::Private, console::U8::new(byte))).collect::<Vec<_>>();
+        let length = U32::new(Mode::Private, console::U32::new(5));
+
+        Circuit::scope("BHP", || {
+            let _candidate = circuit.hash_length_prefixed(&bytes, &length);
+            assert!(!Circuit::is_satisfied_in_scope());
+        });
+        Circuit::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_length_prefixed_rejects_excessive_length() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native);
+
+        // Prepare a length that exceeds the buffer size.
+        let bytes = vec![U8::new(Mode::Private, console::U8::new(0u8)); BUFFER_SIZE];
+        let length = U32::new(Mode::Private, console::U32::new(BUFFER_SIZE as u32 + 1));
+
+        Circuit::scope("BHP", || {
+            let _candidate = circuit.hash_length_prefixed(&bytes, &length);
+            assert!(!Circuit::is_satisfied_in_scope());
+        });
+        Circuit::reset();
+        Ok(())
+    }
+}
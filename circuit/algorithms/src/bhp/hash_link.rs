@@ -0,0 +1,137 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the BHP hash of the given input as a pair of `(digest, public_digest)`,
+    /// where `digest` is the computed hash, and `public_digest` is a new public variable
+    /// that is enforced to be equal to `digest`.
+    ///
+    /// This links two sub-circuits, as the public digest can be exposed as a public input in one scope,
+    /// and consumed as the same public input in another scope.
+    pub fn hash_link(&self, input: &[Boolean<E>]) -> (Field<E>, Field<E>) {
+        // Compute the digest.
+        let digest = self.hash_field(input);
+        // Retrieve the value of the digest.
+        let value = digest.eject_value();
+        // Link the digest to a new public variable.
+        Self::link_digest(digest, value)
+    }
+
+    /// Returns the given digest and a new public variable with the given value,
+    /// enforcing that the public variable is equal to the digest.
+    fn link_digest(digest: Field<E>, value: console::Field<E::Network>) -> (Field<E>, Field<E>) {
+        // Inject the public digest.
+        let public_digest = Field::new(Mode::Public, value);
+        // Ensure the public digest matches the computed digest.
+        E::assert_eq(&digest, &public_digest);
+        (digest, public_digest)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+    const DOMAIN: &str = "BHPCircuit0";
+
+    fn check_hash_link(
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) -> Result<()> {
+        use console::Hash as H;
+
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random input.
+            let input = (0..522).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+            // Compute the expected digest.
+            let expected = native.hash(&input)?;
+            // Prepare the circuit input.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+            // Compute the linked digest in the first scope.
+            let (digest, public_digest) = Circuit::scope(format!("BHP {mode} {i}"), || {
+                let (digest, public_digest) = circuit.hash_link(&circuit_input);
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+                (digest, public_digest)
+            });
+            assert_eq!(expected, digest.eject_value());
+            assert_eq!(Mode::Public, public_digest.eject_mode());
+            assert_eq!(expected, public_digest.eject_value());
+
+            // Consume the public digest in a second scope.
+            Circuit::scope(format!("Consumer {mode} {i}"), || {
+                let expected = Field::<Circuit>::new(Mode::Private, expected);
+                Circuit::assert_eq(&public_digest, expected);
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_link_constant() -> Result<()> {
+        check_hash_link(Mode::Constant, 2, 1, 0, 1)
+    }
+
+    #[test]
+    fn test_hash_link_public() -> Result<()> {
+        check_hash_link(Mode::Public, 409, 1, 895, 896)
+    }
+
+    #[test]
+    fn test_hash_link_private() -> Result<()> {
+        check_hash_link(Mode::Private, 409, 1, 895, 896)
+    }
+
+    #[test]
+    fn test_hash_link_rejects_tampered_public_digest() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native);
+
+        let mut rng = TestRng::default();
+
+        // Sample a random input.
+        let input: Vec<Boolean<_>> =
+            Inject::new(Mode::Private, (0..522).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>());
+
+        // Compute the digest, and link it to a tampered public value.
+        let digest = circuit.hash_field(input);
+        let tampered = digest.eject_value() + console::Field::one();
+        let (_, public_digest) = BHP512::<Circuit>::link_digest(digest, tampered);
+        assert_eq!(tampered, public_digest.eject_value());
+
+        // Ensure the circuit is not satisfied.
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+        Ok(())
+    }
+}
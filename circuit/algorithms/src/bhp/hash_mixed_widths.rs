@@ -0,0 +1,146 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the BHP hash of the given items as a field element, where each item is a field element
+    /// paired with its bit width. Each field element is decomposed into exactly its specified number
+    /// of little-endian bits, enforcing that the remaining upper bits are zero, and the bits are concatenated.
+    pub fn hash_mixed_widths(&self, items: &[(Field<E>, usize)]) -> Field<E> {
+        // Retrieve the field size in bits.
+        let size_in_bits = console::Field::<E::Network>::size_in_bits();
+
+        // Initialize a vector for the hash preimage.
+        let mut preimage = Vec::with_capacity(items.iter().map(|(_, num_bits)| num_bits).sum());
+        for (item, num_bits) in items {
+            match *num_bits {
+                // If the item uses the full field size, decompose it into its canonical bits.
+                num_bits if num_bits == size_in_bits => item.write_bits_le(&mut preimage),
+                // Otherwise, decompose it into its lower bits, which enforces the upper bits are zero.
+                // Note: As `num_bits` is less than the field size, the lower bits cannot wrap around the modulus.
+                num_bits if num_bits < size_in_bits => preimage.extend(item.to_lower_bits_le(num_bits)),
+                num_bits => E::halt(format!("Attempted to hash a {num_bits}-bit item, exceeding {size_in_bits} bits")),
+            }
+        }
+        // Hash the preimage.
+        self.hash_field(&preimage)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+    const DOMAIN: &str = "BHPCircuit0";
+
+    type NativeField = console::Field<<Circuit as Environment>::Network>;
+
+    /// Returns the native BHP hash of the given items, each decomposed into exactly its bit width.
+    fn native_hash_mixed_widths(
+        native: &console::BHP512<<Circuit as Environment>::Network>,
+        items: &[(NativeField, usize)],
+    ) -> Result<NativeField> {
+        use console::{Hash as H, ToBits as TB};
+
+        let mut preimage = Vec::new();
+        for (item, num_bits) in items {
+            let bits_le = item.to_bits_le();
+            assert!(bits_le[*num_bits..].iter().all(|bit| !bit), "The item exceeds its bit width");
+            preimage.extend_from_slice(&bits_le[..*num_bits]);
+        }
+        native.hash(&preimage)
+    }
+
+    /// Samples a record of a `u8` flag, a `u64` amount, and a field element, paired with their bit widths.
+    fn sample_record(rng: &mut TestRng) -> Vec<(NativeField, usize)> {
+        vec![
+            (NativeField::from_u8(u8::rand(rng)), 8),
+            (NativeField::from_u64(u64::rand(rng)), 64),
+            (Uniform::rand(rng), NativeField::size_in_bits()),
+        ]
+    }
+
+    fn check_hash_mixed_widths(
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a mixed-width record.
+            let record = sample_record(&mut rng);
+            // Compute the expected digest.
+            let expected = native_hash_mixed_widths(&native, &record)?;
+            // Prepare the circuit record.
+            let circuit_record =
+                record.iter().map(|(item, num_bits)| (Field::new(mode, *item), *num_bits)).collect::<Vec<_>>();
+
+            Circuit::scope(format!("BHP {mode} {i}"), || {
+                let candidate = circuit.hash_mixed_widths(&circuit_record);
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+                assert_eq!(expected, candidate.eject_value());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_mixed_widths_constant() -> Result<()> {
+        check_hash_mixed_widths(Mode::Constant, 327, 0, 0, 0)
+    }
+
+    #[test]
+    fn test_hash_mixed_widths_public() -> Result<()> {
+        check_hash_mixed_widths(Mode::Public, 407, 0, 1141, 1145)
+    }
+
+    #[test]
+    fn test_hash_mixed_widths_private() -> Result<()> {
+        check_hash_mixed_widths(Mode::Private, 407, 0, 1141, 1145)
+    }
+
+    #[test]
+    fn test_hash_mixed_widths_rejects_wide_items() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native);
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Construct a record with a flag that exceeds 8 bits.
+            let circuit_record = vec![
+                (Field::new(mode, NativeField::from_u16(256)), 8),
+                (Field::new(mode, NativeField::from_u64(1)), 64),
+                (Field::new(mode, NativeField::one()), NativeField::size_in_bits()),
+            ];
+            let result = std::panic::catch_unwind(|| circuit.hash_mixed_widths(&circuit_record));
+            assert!(result.is_err() || !Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+}
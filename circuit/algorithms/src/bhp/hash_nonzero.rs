@@ -0,0 +1,95 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the BHP hash of the given input as a field element, and enforces the digest is nonzero
+    /// (e.g. for use as a nullifier).
+    ///
+    /// The digest is enforced to be nonzero by witnessing its inverse, and enforcing `digest * inverse == 1`,
+    /// which costs 1 constraint. If the digest is zero (which is astronomically unlikely, as it requires
+    /// a preimage of the zero digest), no inverse exists, so the circuit is unsatisfiable, and no proof can be made.
+    /// If the input is constant, a zero digest halts instead.
+    pub fn hash_nonzero(&self, input: &[Boolean<E>]) -> Field<E> {
+        // Compute the digest.
+        let digest = self.hash_field(input);
+        // Ensure the digest is nonzero, by enforcing it has an inverse.
+        let _inverse = digest.inverse(); // 1 constraint
+        digest
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+    const DOMAIN: &str = "BHPCircuit0";
+
+    fn check_hash_nonzero(
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) -> Result<()> {
+        use console::Hash as H;
+
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+        Circuit::reset();
+
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random input.
+            let input = (0..512).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+            // Compute the expected hash.
+            let expected = native.hash(&input)?;
+            // Prepare the circuit input.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+            Circuit::scope(format!("BHP {mode} {i}"), || {
+                // Perform the hash operation.
+                let candidate = circuit.hash_nonzero(&circuit_input);
+                assert_eq!(expected, candidate.eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_nonzero_constant() -> Result<()> {
+        check_hash_nonzero(Mode::Constant, 3, 0, 0, 0)
+    }
+
+    #[test]
+    fn test_hash_nonzero_public() -> Result<()> {
+        check_hash_nonzero(Mode::Public, 409, 0, 881, 881)
+    }
+
+    #[test]
+    fn test_hash_nonzero_private() -> Result<()> {
+        check_hash_nonzero(Mode::Private, 409, 0, 881, 881)
+    }
+}
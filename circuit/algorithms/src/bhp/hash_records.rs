@@ -0,0 +1,83 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the BHP hash of the given fixed-size records as a field element, which equals the hash of the
+    /// concatenated records. As each record is an array of `RECORD_BITS` bits, the input length is guaranteed
+    /// to be a multiple of the record size, so a truncated or misaligned record cannot be hashed.
+    pub fn hash_records<const RECORD_BITS: usize>(&self, records: &[[Boolean<E>; RECORD_BITS]]) -> Field<E> {
+        self.hash_field(records.concat())
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "BHPCircuit0";
+
+    /// Checks that hashing the given number of records of `RECORD_BITS` bits matches the flattened hash.
+    fn check_hash_records<const RECORD_BITS: usize>(num_records: usize) -> Result<()> {
+        use console::Hash as H;
+
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+        Circuit::reset();
+
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Sample the records.
+            let records = (0..num_records)
+                .map(|_| {
+                    let record = (0..RECORD_BITS).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+                    let record: Vec<Boolean<_>> = Inject::new(mode, record);
+                    record.try_into().unwrap()
+                })
+                .collect::<Vec<[Boolean<_>; RECORD_BITS]>>();
+            // Compute the expected hash of the flattened records.
+            let flattened = records.iter().flatten().cloned().collect::<Vec<_>>();
+            let expected = native.hash(&flattened.eject_value())?;
+
+            // Ensure the records hash equals the flattened hash, with the same counts.
+            let (expected_flattened, expected_count) =
+                Circuit::scope("BHP flattened", || (circuit.hash_field(&flattened), Circuit::count_in_scope()));
+            let (candidate, candidate_count) =
+                Circuit::scope("BHP records", || (circuit.hash_records(&records), Circuit::count_in_scope()));
+            assert_eq!(expected, expected_flattened.eject_value());
+            assert_eq!(expected, candidate.eject_value());
+            assert_eq!(expected_count, candidate_count);
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_records() -> Result<()> {
+        // Records within one iteration.
+        check_hash_records::<64>(4)?;
+        // Records spanning multiple iterations, which are not aligned to an iteration.
+        check_hash_records::<100>(7)?;
+        // A single record.
+        check_hash_records::<1>(1)
+    }
+}
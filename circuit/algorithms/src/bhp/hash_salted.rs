@@ -0,0 +1,104 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::{CryptoRng, Rng};
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the BHP hash of the given input with a freshly-sampled salt, as a field element,
+    /// along with the `NUM_SALT_BITS` salt bits as private witnesses.
+    ///
+    /// The preimage is `[ SALT || INPUT ]`, so the digest can later be opened
+    /// by rehashing the returned salt bits together with the input.
+    pub fn hash_salted<const NUM_SALT_BITS: usize, R: Rng + CryptoRng>(
+        &self,
+        input: &[Boolean<E>],
+        rng: &mut R,
+    ) -> (Field<E>, Vec<Boolean<E>>) {
+        // Sample the salt bits as private witnesses.
+        let salt = (0..NUM_SALT_BITS).map(|_| Boolean::new(Mode::Private, rng.gen())).collect::<Vec<_>>();
+
+        // Initialize a vector for the hash preimage.
+        let mut preimage = Vec::with_capacity(NUM_SALT_BITS + input.len());
+        preimage.extend_from_slice(&salt);
+        preimage.extend_from_slice(input);
+
+        // Hash the preimage.
+        (self.hash_field(&preimage), salt)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+    const DOMAIN: &str = "BHPCircuit0";
+    const NUM_SALT_BITS: usize = 128;
+
+    fn check_hash_salted(mode: Mode) -> Result<()> {
+        use console::Hash as H;
+
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random input.
+            let input = (0..256).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+            // Prepare the circuit input.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input.clone());
+
+            Circuit::scope(format!("BHP {mode} {i}"), || {
+                // Perform the salted hash operation.
+                let (candidate, salt) = circuit.hash_salted::<NUM_SALT_BITS, _>(&circuit_input, &mut rng);
+                assert_eq!(NUM_SALT_BITS, salt.len());
+                assert!(salt.iter().all(|bit| bit.eject_mode() == Mode::Private));
+
+                // Ensure rehashing with the returned salt reproduces the digest.
+                let preimage = [salt.clone(), circuit_input.clone()].concat();
+                assert_eq!(candidate.eject_value(), circuit.hash(&preimage).eject_value());
+
+                // Ensure the digest matches the native hash of the salted input.
+                let native_preimage = [salt.eject_value(), input.clone()].concat();
+                assert_eq!(native.hash(&native_preimage).unwrap(), candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_salted_constant() -> Result<()> {
+        check_hash_salted(Mode::Constant)
+    }
+
+    #[test]
+    fn test_hash_salted_public() -> Result<()> {
+        check_hash_salted(Mode::Public)
+    }
+
+    #[test]
+    fn test_hash_salted_private() -> Result<()> {
+        check_hash_salted(Mode::Private)
+    }
+}
@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns a Pedersen-style hash of the given tiny input as a field element, computed as
+    /// the x-coordinate of the sum of `bit_i * base_i` over the bases of this BHP (in window order).
+    ///
+    /// This is a fallback for inputs below the minimum size of the BHP hasher, and is a different function
+    /// from `hash` (e.g. there is no domain or length prefix). An input with trailing `false` bits has the same
+    /// digest as the input without them, so callers must fix the input length. The input cannot exceed
+    /// `NUM_WINDOWS * WINDOW_SIZE` bits, which is the number of bases.
+    pub fn hash_small(&self, input: &[Boolean<E>]) -> Field<E> {
+        // Ensure the input does not exceed the number of bases.
+        let max_bits = NUM_WINDOWS as usize * WINDOW_SIZE as usize;
+        if input.len() > max_bits {
+            E::halt(BHPError::InputTooLong { num_bits: input.len(), max_bits }.to_string())
+        }
+
+        // Recover the native twisted Edwards bases from their first Montgomery lookup.
+        let one = console::Field::<E::Network>::one();
+        let bases = self.hasher.bases().iter().flatten().map(|(x_bases, y_bases)| {
+            let (x, y) = (x_bases[0].eject_value(), y_bases[0].eject_value());
+            Group::constant(console::Group::from_xy_coordinates_unchecked(x / y, (x - one) / (x + one)))
+        });
+
+        // Compute the sum of base_i^{input_i} for all i.
+        input
+            .iter()
+            .zip(bases)
+            .map(|(bit, base)| Group::ternary(bit, &base, &Group::zero()))
+            .fold(Group::<E>::zero(), |acc, x| acc + x)
+            .to_x_coordinate()
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+    const DOMAIN: &str = "BHPCircuit0";
+    const NUM_BITS: usize = 8;
+
+    fn check_hash_small(
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+        Circuit::reset();
+
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random input, below the minimum size of the BHP hasher.
+            let input = (0..NUM_BITS).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+            // Compute the expected hash, as the native sum of the bases selected by the input.
+            let expected = input
+                .iter()
+                .zip(native.bases().iter().flatten())
+                .filter(|(bit, _)| **bit)
+                .fold(console::Group::zero(), |acc, (_, base)| acc + base)
+                .to_x_coordinate();
+            // Prepare the circuit input.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+            Circuit::scope(format!("BHP {mode} {i}"), || {
+                // Perform the hash operation.
+                let candidate = circuit.hash_small(&circuit_input);
+                assert_eq!(expected, candidate.eject_value());
+                assert_scope!(<=num_constants, num_public, num_private, num_constraints);
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_small_constant() -> Result<()> {
+        check_hash_small(Mode::Constant, 108, 0, 0, 0)
+    }
+
+    #[test]
+    fn test_hash_small_public() -> Result<()> {
+        check_hash_small(Mode::Public, 94, 0, 42, 42)
+    }
+
+    #[test]
+    fn test_hash_small_private() -> Result<()> {
+        check_hash_small(Mode::Private, 94, 0, 42, 42)
+    }
+
+    #[test]
+    fn test_hash_small_halts_above_number_of_bases() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native);
+
+        // Ensure an input exceeding the number of bases halts.
+        let input: Vec<Boolean<_>> = Inject::new(Mode::Private, vec![true; 6 * 43 + 1]);
+        let result = std::panic::catch_unwind(|| circuit.hash_small(&input));
+        assert!(result.is_err());
+        Circuit::reset();
+        Ok(())
+    }
+}
@@ -0,0 +1,254 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the BHP hash of the given input as a field element, where each window of the input
+    /// is prefixed with the bits of its window-specific constant tag for domain separation.
+    ///
+    /// The input is split into windows of `WINDOW_SIZE * BHP_CHUNK_SIZE` bits (with the last window
+    /// possibly shorter), and each window `i` is hashed directly after the bits of `tags[i]` in the preimage
+    /// of an iteration (which begins with `DATA_BITS` bits of domain and length, or of the previous digest).
+    /// Each window of the input starts at a window boundary of the bases, and its tag is packed into the bits
+    /// directly before it, so a tag shares the windows of the bases before its input window with the padding
+    /// (or the previous window of the input). If a tag and its window do not fit in the rest of an iteration,
+    /// the iteration is padded with `false` bits, and they are hashed in the next iteration.
+    ///
+    /// This method halts if a tag is not a constant, if the number of tags does not match the number of windows,
+    /// or if a tag and its window do not fit in one iteration.
+    pub fn hash_tagged(&self, tags: &[Field<E>], input: &[Boolean<E>]) -> Field<E> {
+        // Determine the number of bits in each window.
+        let window_size_in_bits = WINDOW_SIZE as usize * BHP_CHUNK_SIZE;
+        // Determine the number of windows in the input.
+        let num_windows = (input.len() + window_size_in_bits - 1) / window_size_in_bits;
+
+        // Ensure there is exactly one tag for each window.
+        if tags.len() != num_windows {
+            E::halt(format!("Expected {num_windows} tags for the BHP windows, found {} tags", tags.len()))
+        }
+        // Ensure the tags are constant.
+        if tags.iter().any(|tag| !tag.is_constant()) {
+            E::halt("The tags for the BHP windows must be constant")
+        }
+
+        // Returns the given position in the preimage of an iteration, rounded up to a window boundary.
+        let align = |position: usize| (position + window_size_in_bits - 1) / window_size_in_bits * window_size_in_bits;
+
+        // The number of data bits that prefix the preimage of each iteration.
+        let num_data_bits = E::BaseField::size_in_data_bits();
+        // The number of bits in the preimage of each iteration, including its prefix.
+        let num_iteration_bits = match Self::max_input_bits_per_iteration() {
+            Ok(max_input_bits_per_iteration) => num_data_bits + max_input_bits_per_iteration,
+            Err(error) => E::halt(error.to_string()),
+        };
+        // The number of bits in each tag.
+        let num_tag_bits = console::Field::<E::Network>::size_in_bits();
+
+        // Returns the start of the window of the input, for a tag packed at the given position in an iteration.
+        let window_start = |position: usize| align(position + num_tag_bits);
+
+        // Ensure a tag and its window fit in the bits after the prefix of an iteration.
+        if window_start(num_data_bits) + window_size_in_bits > num_iteration_bits {
+            E::halt(format!(
+                "A BHP tag of {num_tag_bits} bits and its window do not fit in an iteration of {NUM_WINDOWS} windows"
+            ))
+        }
+
+        // Initialize the scratch buffer for the tagged input, with the capacity of each tag and window aligned.
+        let mut scratch = Vec::with_capacity(num_windows * align(num_tag_bits + window_size_in_bits));
+        // Appends the given number of `false` bits to the tagged input.
+        let pad = |scratch: &mut Vec<Boolean<E>>, num_bits: usize| {
+            scratch.resize(scratch.len() + num_bits, Boolean::constant(false))
+        };
+
+        // Track the position in the preimage of the current iteration, which begins after the prefix.
+        let mut position = num_data_bits;
+        for (tag, window) in tags.iter().zip_eq(input.chunks(window_size_in_bits)) {
+            // If the tag and its window do not fit in the rest of this iteration, pad to the next iteration.
+            if window_start(position) + window.len() > num_iteration_bits {
+                pad(&mut scratch, num_iteration_bits - position);
+                position = num_data_bits;
+            }
+            // Pad up to the tag, so that the tag ends where its window starts, at a window boundary.
+            let start = window_start(position);
+            pad(&mut scratch, start - num_tag_bits - position);
+            tag.write_bits_le(&mut scratch);
+            // Write the window of the input.
+            scratch.extend_from_slice(window);
+            position = start + window.len();
+        }
+
+        // Hash the tagged input, writing the preimage of each iteration after it.
+        match self.try_hash_iterations_in_scratch(None, &mut scratch) {
+            Ok(digest) => digest.to_x_coordinate(),
+            Err(error) => E::halt(error.to_string()),
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+    const DOMAIN: &str = "BHPCircuit0";
+
+    type NativeField = console::Field<<Circuit as Environment>::Network>;
+
+    /// Returns the native BHP512 hash of the given input, with each window prefixed by its tag.
+    ///
+    /// Each iteration of BHP512 has 6 windows of 129 bits, and begins with a prefix of 252 bits.
+    /// So, each window of the input starts at bit 516 of an iteration, as its tag (253 bits) is packed directly
+    /// before it, from bit 263. The last window of the iteration is left as padding, as the next tag and window
+    /// do not fit in it. The tagged input of each iteration is thus: `[ 0^11 || TAG || WINDOW || 0^129 ]`.
+    fn native_hash_tagged(
+        native: &console::BHP512<<Circuit as Environment>::Network>,
+        tags: &[NativeField],
+        input: &[bool],
+    ) -> Result<NativeField> {
+        use console::{Hash as H, ToBits as TB};
+
+        let windows = input.chunks(129).collect::<Vec<_>>();
+        assert_eq!(tags.len(), windows.len());
+
+        let mut tagged = Vec::new();
+        for (i, (tag, window)) in tags.iter().zip(windows).enumerate() {
+            // Pad the end of the previous iteration.
+            if i > 0 {
+                tagged.extend([false; 129]);
+            }
+            tagged.extend([false; 11]);
+            tagged.extend(tag.to_bits_le());
+            tagged.extend_from_slice(window);
+        }
+        native.hash(&tagged)
+    }
+
+    fn check_hash_tagged(
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random input spanning three windows, with a partial last window.
+            let input = (0..300).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+            // Sample a tag for each window.
+            let tags = (0..3).map(|_| Uniform::rand(&mut rng)).collect::<Vec<NativeField>>();
+            // Compute the expected digest.
+            let expected = native_hash_tagged(&native, &tags, &input)?;
+            // Prepare the circuit input and tags.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+            let circuit_tags = tags.iter().map(|tag| Field::constant(*tag)).collect::<Vec<_>>();
+
+            Circuit::scope(format!("BHP {mode} {i}"), || {
+                let candidate = circuit.hash_tagged(&circuit_tags, &circuit_input);
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+                assert_eq!(expected, candidate.eject_value());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_tagged_constant() -> Result<()> {
+        check_hash_tagged(Mode::Constant, 761, 0, 0, 0)
+    }
+
+    #[test]
+    fn test_hash_tagged_public() -> Result<()> {
+        check_hash_tagged(Mode::Public, 2588, 0, 2388, 2392)
+    }
+
+    #[test]
+    fn test_hash_tagged_private() -> Result<()> {
+        check_hash_tagged(Mode::Private, 2588, 0, 2388, 2392)
+    }
+
+    #[test]
+    fn test_hash_tagged_distinct_tags() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native);
+
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Sample a random input spanning two windows.
+            let input: Vec<Boolean<_>> = Inject::new(mode, (0..200).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>());
+            // Sample two tag sets that differ only in the last tag.
+            let first = [Field::constant(Uniform::rand(&mut rng)), Field::constant(Uniform::rand(&mut rng))];
+            let second = [first[0].clone(), Field::constant(Uniform::rand(&mut rng))];
+
+            // Ensure the tag sets yield distinct digests for the same input.
+            let a = circuit.hash_tagged(&first, &input);
+            let b = circuit.hash_tagged(&second, &input);
+            let c = circuit.hash_tagged(&first, &input);
+            assert_ne!(a.eject_value(), b.eject_value());
+            assert_eq!(a.eject_value(), c.eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_tagged_rejects_mismatched_tags() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native);
+
+        let mut rng = TestRng::default();
+
+        // Sample a random input spanning two windows, with a single tag.
+        let input: Vec<Boolean<_>> =
+            Inject::new(Mode::Private, (0..200).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>());
+        let tags = [Field::constant(Uniform::rand(&mut rng))];
+        let result = std::panic::catch_unwind(|| circuit.hash_tagged(&tags, &input));
+        assert!(result.is_err());
+        Circuit::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_tagged_rejects_small_iterations() -> Result<()> {
+        // Initialize BHP256, which has 3 windows of 171 bits, so a tag and its window do not fit after the prefix.
+        let native = console::BHP256::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP256::<Circuit>::constant(native);
+
+        let mut rng = TestRng::default();
+
+        // Sample a random input spanning one window, with a single tag.
+        let input: Vec<Boolean<_>> =
+            Inject::new(Mode::Private, (0..100).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>());
+        let tags = [Field::constant(Uniform::rand(&mut rng))];
+        let result = std::panic::catch_unwind(|| circuit.hash_tagged(&tags, &input));
+        assert!(result.is_err());
+        Circuit::reset();
+        Ok(())
+    }
+}
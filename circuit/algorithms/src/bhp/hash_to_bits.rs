@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the BHP hash of the given input as the little-endian bits of a field element.
+    ///
+    /// The bits are the unique (canonical) representation of the digest, i.e. they are enforced
+    /// to be less than the base field modulus, so they may be fed directly into another bit-consuming gadget.
+    pub fn hash_to_bits(&self, input: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        self.hash_field(input).to_bits_le()
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+    const DOMAIN: &str = "BHPCircuit0";
+
+    fn check_hash_to_bits(
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) -> Result<()> {
+        use console::{Hash as H, ToBits as TB};
+
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random input.
+            let input = (0..522).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+            // Compute the expected bits.
+            let expected = native.hash(&input)?.to_bits_le();
+            // Prepare the circuit input.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+            Circuit::scope(format!("BHP {mode} {i}"), || {
+                let candidate = circuit.hash_to_bits(&circuit_input);
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+                assert_eq!(expected, candidate.eject_value());
+            });
+
+            // Ensure the bits match the bits of the field digest.
+            let digest = circuit.hash(&circuit_input);
+            assert_eq!(expected, digest.to_bits_le().eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_to_bits_constant() -> Result<()> {
+        check_hash_to_bits(Mode::Constant, 255, 0, 0, 0)
+    }
+
+    #[test]
+    fn test_hash_to_bits_public() -> Result<()> {
+        check_hash_to_bits(Mode::Public, 409, 0, 1400, 1402)
+    }
+
+    #[test]
+    fn test_hash_to_bits_private() -> Result<()> {
+        check_hash_to_bits(Mode::Private, 409, 0, 1400, 1402)
+    }
+}
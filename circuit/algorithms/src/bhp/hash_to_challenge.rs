@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns a Fiat-Shamir challenge scalar from hashing the given transcript bits.
+    ///
+    /// The digest is reduced modulo the scalar field modulus, and the reduction is enforced
+    /// to be canonical, so the challenge matches `console::Scalar::from_field_mod` of the native hash.
+    pub fn hash_to_challenge(&self, transcript_bits: &[Boolean<E>]) -> Scalar<E> {
+        // Hash the transcript to the base field.
+        let digest = self.hash_field(transcript_bits);
+        // Reduce the digest to the scalar field.
+        Scalar::from_field_mod(&digest)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+    const DOMAIN: &str = "BHPCircuit0";
+
+    fn check_hash_to_challenge(
+        mode: Mode,
+        num_bits: usize,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) -> Result<()> {
+        use console::Hash as H;
+
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+        Circuit::reset();
+
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random transcript.
+            let transcript = (0..num_bits).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+            // Compute the expected challenge.
+            let expected = console::Scalar::from_field_mod(&native.hash(&transcript)?);
+            // Prepare the circuit transcript.
+            let circuit_transcript: Vec<Boolean<_>> = Inject::new(mode, transcript);
+
+            Circuit::scope(format!("BHP {mode} {i}"), || {
+                // Perform the challenge derivation.
+                let candidate = circuit.hash_to_challenge(&circuit_transcript);
+                assert_eq!(expected, candidate.eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_to_challenge_constant() -> Result<()> {
+        check_hash_to_challenge(Mode::Constant, 256, 3, 0, 0, 0)?;
+        check_hash_to_challenge(Mode::Constant, 600, 3, 0, 0, 0)
+    }
+
+    #[test]
+    fn test_hash_to_challenge_public() -> Result<()> {
+        check_hash_to_challenge(Mode::Public, 256, 406, 0, 1201, 1204)?;
+        check_hash_to_challenge(Mode::Public, 600, 417, 0, 2716, 2721)
+    }
+
+    #[test]
+    fn test_hash_to_challenge_private() -> Result<()> {
+        check_hash_to_challenge(Mode::Private, 256, 406, 0, 1201, 1204)?;
+        check_hash_to_challenge(Mode::Private, 600, 417, 0, 2716, 2721)
+    }
+}
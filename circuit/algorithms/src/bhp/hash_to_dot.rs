@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the BHP hash of the given input as a field element, along with the dependency graph
+    /// of the constraints of the hash in the DOT format (e.g. to visualize the Montgomery addition chain).
+    pub fn hash_to_dot(&self, input: &[Boolean<E>]) -> (Field<E>, String) {
+        E::capture_dot_graph(|| self.hash_field(input))
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "BHPCircuit0";
+
+    #[test]
+    fn test_hash_to_dot() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP256::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP256::<Circuit>::constant(native);
+        Circuit::reset();
+
+        // Hash a small private input.
+        let input: Vec<Boolean<_>> =
+            Inject::new(Mode::Private, vec![true, false, true, true, false, false, true, false]);
+        let count = Circuit::count();
+        let (digest, dot) = circuit.hash_to_dot(&input);
+
+        // Ensure the digest is unchanged, and the circuit is restored with the constraints of the hash.
+        assert_eq!(circuit.hash_field(&input).eject_value(), digest.eject_value());
+        assert!(count.3 < Circuit::num_constraints());
+        assert!(Circuit::is_satisfied());
+
+        // Ensure the graph has a node for each constraint of the hash, and only the constraints of the hash.
+        assert!(dot.starts_with("digraph R1CS {\n") && dot.ends_with("}\n"));
+        assert!(dot.contains(" -> constraint_"));
+        assert!(!dot.contains(&format!("constraint_{} ", count.3 - 1)));
+        assert!(dot.contains(&format!("constraint_{} ", count.3)));
+        Circuit::reset();
+        Ok(())
+    }
+}
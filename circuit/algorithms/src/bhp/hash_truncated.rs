@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the low `num_bits` little-endian bits of the canonical representation of the BHP hash
+    /// of the given input (e.g. for a short commitment).
+    ///
+    /// Note: Truncating the digest to `num_bits` bits reduces its collision resistance to roughly
+    /// `2^(num_bits / 2)`, and its preimage resistance to roughly `2^num_bits`, so `num_bits` must be chosen
+    /// for the required security level. The decomposition is canonical, so the cost equals `hash_to_bits`.
+    pub fn hash_truncated(&self, input: &[Boolean<E>], num_bits: usize) -> Vec<Boolean<E>> {
+        // Ensure the number of bits does not exceed the size of the digest.
+        let size_in_bits = console::Field::<E::Network>::size_in_bits();
+        if num_bits > size_in_bits {
+            E::halt(format!("Cannot truncate a BHP digest of {size_in_bits} bits to {num_bits} bits"))
+        }
+        let mut bits = self.hash_to_bits(input);
+        bits.truncate(num_bits);
+        bits
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+    const DOMAIN: &str = "BHPCircuit0";
+    const NUM_BITS: usize = 128;
+
+    fn check_hash_truncated(
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) -> Result<()> {
+        use console::{Hash as H, ToBits as TB};
+
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random input.
+            let input = (0..522).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+            // Compute the expected bits, as the low bits of the native digest.
+            let expected = native.hash(&input)?.to_bits_le()[..NUM_BITS].to_vec();
+            // Prepare the circuit input.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+            Circuit::scope(format!("BHP {mode} {i}"), || {
+                let candidate = circuit.hash_truncated(&circuit_input, NUM_BITS);
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+                assert_eq!(expected, candidate.eject_value());
+            });
+
+            // Ensure the bits match the low bits of the full digest bits.
+            let bits = circuit.hash_to_bits(&circuit_input);
+            assert_eq!(expected, bits[..NUM_BITS].to_vec().eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_truncated_constant() -> Result<()> {
+        check_hash_truncated(Mode::Constant, 255, 0, 0, 0)
+    }
+
+    #[test]
+    fn test_hash_truncated_public() -> Result<()> {
+        check_hash_truncated(Mode::Public, 409, 0, 1400, 1402)
+    }
+
+    #[test]
+    fn test_hash_truncated_private() -> Result<()> {
+        check_hash_truncated(Mode::Private, 409, 0, 1400, 1402)
+    }
+
+    #[test]
+    fn test_hash_truncated_sizes() -> Result<()> {
+        use console::{Hash as H, ToBits as TB};
+
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+
+        let mut rng = TestRng::default();
+
+        let input = (0..522).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+        let expected = native.hash(&input)?.to_bits_le();
+        let circuit_input: Vec<Boolean<_>> = Inject::new(Mode::Private, input);
+
+        // Ensure each truncation is the prefix of the full digest bits.
+        for num_bits in [0, 1, 64, 252, 253] {
+            let candidate = circuit.hash_truncated(&circuit_input, num_bits);
+            assert_eq!(expected[..num_bits], candidate.eject_value());
+        }
+        assert!(Circuit::is_satisfied());
+
+        // Ensure a truncation beyond the size of the digest halts.
+        let result = std::panic::catch_unwind(|| circuit.hash_truncated(&circuit_input, 254));
+        assert!(result.is_err());
+        Circuit::reset();
+        Ok(())
+    }
+}
@@ -14,6 +14,8 @@
 
 use super::*;
 
+use core::ops::Range;
+
 impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> HashUncompressed
     for BHP<E, NUM_WINDOWS, WINDOW_SIZE>
 {
@@ -24,52 +26,219 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> HashUncompres
     ///
     /// This uncompressed variant of the BHP hash function is provided to support
     /// the BHP commitment scheme, as it is typically not used by applications.
+    ///
+    /// This method halts if an iteration is given an invalid input size, which is suitable for the prover.
+    /// To surface the error to the caller instead, use `BHP::try_hash_uncompressed`.
     fn hash_uncompressed(&self, input: &[Self::Input]) -> Self::Output {
-        // The number of hasher bits to fit.
-        let num_hasher_bits = NUM_WINDOWS as usize * WINDOW_SIZE as usize * BHP_CHUNK_SIZE;
+        match self.try_hash_uncompressed(input) {
+            Ok(digest) => digest,
+            Err(error) => E::halt(error.to_string()),
+        }
+    }
+}
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the BHP hash of the given input as an affine group element,
+    /// or a `BHPError` if an iteration is given an invalid input size, instead of halting.
+    pub fn try_hash_uncompressed(&self, input: &[Boolean<E>]) -> Result<Group<E>, BHPError> {
+        self.try_hash_iterations(None, input)
+    }
+
+    /// Returns the maximum number of input bits per iteration, computed with checked arithmetic,
+    /// or a `BHPError` if the parameters overflow, or do not exceed the number of data bits.
+    pub(super) fn max_input_bits_per_iteration() -> Result<usize, BHPError> {
+        checked_max_input_bits_per_iteration(
+            NUM_WINDOWS as usize,
+            WINDOW_SIZE as usize,
+            E::BaseField::size_in_data_bits(),
+        )
+    }
+
+    /// Returns the BHP hash of the given input as an affine group element, along with the indices
+    /// of the windows of bases consumed in each iteration, for auditing which bases the input touched.
+    ///
+    /// Each iteration consumes a contiguous prefix of the windows, as a short preimage is zipped with the bases.
+    pub fn hash_uncompressed_with_window_indices(&self, input: &[Boolean<E>]) -> (Group<E>, Vec<Vec<usize>>) {
+        // The number of data bits in the output.
+        let num_data_bits = E::BaseField::size_in_data_bits();
+        // The maximum number of input bits per iteration.
+        let max_input_bits_per_iteration = match Self::max_input_bits_per_iteration() {
+            Ok(max_input_bits_per_iteration) => max_input_bits_per_iteration,
+            Err(error) => E::halt(error.to_string()),
+        };
+
+        // Determine the indices consumed in each iteration, where each preimage is `num_data_bits` followed by the input bits.
+        let indices = input
+            .chunks(max_input_bits_per_iteration)
+            .map(|input_bits| self.hasher.window_indices(num_data_bits + input_bits.len()))
+            .collect();
+
+        (self.hash_uncompressed(input), indices)
+    }
+
+    /// Returns the BHP hash of the given input as an affine group element, by hashing each iteration
+    /// with the given shared context if one is provided, or with a new context per iteration otherwise.
+    pub(super) fn try_hash_iterations(
+        &self,
+        context: Option<&BHPContext<'_, E>>,
+        input: &[Boolean<E>],
+    ) -> Result<Group<E>, BHPError> {
+        self.try_hash_iterations_with_scratch(context, input, &mut Vec::new())
+    }
+
+    /// Returns the BHP hash of the given input as an affine group element, as in `try_hash_iterations`,
+    /// where the padded preimage of each iteration is written into the given scratch buffer.
+    ///
+    /// The buffer is cleared before use, and reserved to fit the largest preimage,
+    /// so a buffer reused across calls is allocated at most once.
+    ///
+    /// This is the entry point of every BHP hash of an input. If the input is entirely constant and the native BHP
+    /// is known, the digest is computed by the native BHP and injected as a constant, and the buffer is not used,
+    /// unless the given context maps the windows, which the native BHP does not support.
+    pub(super) fn try_hash_iterations_with_scratch(
+        &self,
+        context: Option<&BHPContext<'_, E>>,
+        input: &[Boolean<E>],
+        preimage: &mut Vec<Boolean<E>>,
+    ) -> Result<Group<E>, BHPError> {
+        // If the input is entirely constant, hash it natively.
+        if let Some(digest) = self.hash_natively(context, input) {
+            return Ok(digest);
+        }
+
+        // Hash the preimages in the cleared buffer, copying each block of the input into its preimage.
+        preimage.clear();
+        self.try_hash_preimages(context, input.len(), preimage, |preimage, block| {
+            preimage.extend_from_slice(&input[block])
+        })
+    }
+
+    /// Returns the BHP hash of the input given as the contents of the scratch buffer, as an affine group element,
+    /// as in `try_hash_iterations_with_scratch`, where the padded preimage of each iteration is written into
+    /// the buffer after the input. This lets a caller that collects or owns the input hash it without a separate
+    /// buffer for the preimages. The buffer is truncated back to the input afterwards.
+    pub(super) fn try_hash_iterations_in_scratch(
+        &self,
+        context: Option<&BHPContext<'_, E>>,
+        scratch: &mut Vec<Boolean<E>>,
+    ) -> Result<Group<E>, BHPError> {
+        // If the input is entirely constant, hash it natively.
+        if let Some(digest) = self.hash_natively(context, scratch) {
+            return Ok(digest);
+        }
+
+        // Hash the preimages after the input, copying each block of the input into its preimage.
+        let num_input_bits = scratch.len();
+        let digest = self
+            .try_hash_preimages(context, num_input_bits, scratch, |scratch, block| scratch.extend_from_within(block));
+        // Truncate the buffer back to the input.
+        scratch.truncate(num_input_bits);
+        digest
+    }
+
+    /// Returns the digest of the given input computed by the native BHP, as a constant affine group element,
+    /// if the input is entirely constant, the native BHP is known, and the given context does not map the windows.
+    ///
+    /// Note: If the native hash fails, `None` is returned, and the circuit hash reports the error.
+    fn hash_natively(&self, context: Option<&BHPContext<'_, E>>, input: &[Boolean<E>]) -> Option<Group<E>> {
+        #[cfg(console)]
+        if let Some(native) = &self.native {
+            let is_mapped = context.map_or(false, |context| context.window_map.is_some());
+            if !is_mapped && input.iter().all(|bit| bit.is_constant()) {
+                let bits = input.iter().map(Eject::eject_value).collect::<Vec<bool>>();
+                if let Ok(digest) = console::HashUncompressed::hash_uncompressed(native, &bits) {
+                    // Record that the digest was computed natively, if the trace is recorded.
+                    context.into_iter().for_each(|context| context.record(|trace| trace.is_native = true));
+                    // Note: The native digest is in the group, so its membership is not checked again.
+                    let (x, y) = (Field::constant(digest.to_x_coordinate()), Field::constant(digest.to_y_coordinate()));
+                    return Some(Group::from_xy_coordinates_unchecked(x, y));
+                }
+            }
+        }
+        #[cfg(not(console))]
+        let _ = (context, input);
+        None
+    }
+
+    /// Returns the BHP hash of an input of the given number of bits as an affine group element, where the padded
+    /// preimage of each iteration is written at the end of the given buffer, and the given function appends
+    /// the given block of the input to it. The buffer is restored to its original length after each iteration.
+    fn try_hash_preimages(
+        &self,
+        context: Option<&BHPContext<'_, E>>,
+        num_input_bits: usize,
+        buffer: &mut Vec<Boolean<E>>,
+        append_block: impl Fn(&mut Vec<Boolean<E>>, Range<usize>),
+    ) -> Result<Group<E>, BHPError> {
         // The number of data bits in the output.
         let num_data_bits = E::BaseField::size_in_data_bits();
         // The maximum number of input bits per iteration.
-        let max_input_bits_per_iteration = num_hasher_bits - num_data_bits;
+        let max_input_bits_per_iteration = Self::max_input_bits_per_iteration()?;
+        // The number of hasher bits to fit.
+        let num_hasher_bits = max_input_bits_per_iteration + num_data_bits;
 
-        debug_assert!(num_data_bits < num_hasher_bits);
         debug_assert_eq!(num_data_bits - 64, self.domain.len());
 
         // Initialize a variable to store the hash from the current iteration.
         let mut digest = Group::zero();
 
-        // Prepare a reusable vector for the preimage.
-        let mut preimage = Vec::with_capacity(num_hasher_bits);
+        // Prepare the buffer for the preimage, which starts at the current end of the buffer.
+        // Note: The hasher bits are a multiple of `BHP_CHUNK_SIZE`, so the padded preimage fits.
+        let offset = buffer.len();
+        buffer.reserve(num_hasher_bits);
 
         // Compute the hash of the input.
-        for (i, input_bits) in input.chunks(max_input_bits_per_iteration).enumerate() {
+        for (i, start) in (0..num_input_bits).step_by(max_input_bits_per_iteration).enumerate() {
+            // Determine the block of the input for this iteration.
+            let block = start..num_input_bits.min(start + max_input_bits_per_iteration);
             // Determine if this is the first iteration.
             match i == 0 {
                 // Construct the first iteration as: [ 0...0 || DOMAIN || LENGTH(INPUT) || INPUT[0..BLOCK_SIZE] ].
                 true => {
-                    // Initialize a vector for the hash preimage.
-                    preimage.extend(self.domain.clone());
-                    U64::constant(console::U64::new(input.len() as u64)).write_bits_le(&mut preimage);
-                    preimage.extend_from_slice(input_bits);
+                    buffer.extend(self.domain.clone());
+                    U64::constant(console::U64::new(num_input_bits as u64)).write_bits_le(buffer);
                 }
                 // Construct the subsequent iterations as: [ PREVIOUS_HASH[0..DATA_BITS] || INPUT[I * BLOCK_SIZE..(I + 1) * BLOCK_SIZE] ].
                 false => {
-                    // Initialize a vector for the hash preimage.
-                    digest.to_x_coordinate().write_bits_le(&mut preimage);
-                    preimage.truncate(num_data_bits);
-                    preimage.extend_from_slice(input_bits);
+                    digest.to_x_coordinate().write_bits_le(buffer);
+                    buffer.truncate(offset + num_data_bits);
                 }
             }
+            append_block(buffer, block);
+            // Pad the preimage to a multiple of `BHP_CHUNK_SIZE`, so the hasher does not copy it to pad it.
+            let padded_len = (buffer.len() - offset + BHP_CHUNK_SIZE - 1) / BHP_CHUNK_SIZE * BHP_CHUNK_SIZE;
+            buffer.resize(offset + padded_len, Boolean::constant(false));
             // Hash the preimage for this iteration.
-            digest = self.hasher.hash_uncompressed(&preimage);
-            // Clear the preimage vector for the next iteration.
-            preimage.clear();
+            let preimage = &buffer[offset..];
+            let result = match context {
+                Some(context) => self.hasher.try_hash_uncompressed_in_context(context, preimage),
+                None => self.hasher.try_hash_uncompressed(preimage),
+            };
+            // Restore the buffer for the next iteration.
+            buffer.truncate(offset);
+            digest = result?;
         }
 
-        digest
+        Ok(digest)
     }
 }
 
+/// Returns the maximum number of input bits per iteration for the given number of windows and window size,
+/// computed with checked arithmetic, or a `BHPError` if the computation overflows, or the hasher bits
+/// do not exceed the given number of data bits (which would otherwise wrap to a silently-wrong size).
+fn checked_max_input_bits_per_iteration(
+    num_windows: usize,
+    window_size: usize,
+    num_data_bits: usize,
+) -> Result<usize, BHPError> {
+    num_windows
+        .checked_mul(window_size)
+        .and_then(|num_chunks| num_chunks.checked_mul(BHP_CHUNK_SIZE))
+        .and_then(|num_hasher_bits| num_hasher_bits.checked_sub(num_data_bits))
+        .filter(|max_input_bits_per_iteration| *max_input_bits_per_iteration > 0)
+        .ok_or(BHPError::InvalidParameters { num_windows, window_size, num_data_bits })
+}
+
 #[cfg(all(test, console))]
 mod tests {
     use super::*;
@@ -148,7 +317,7 @@ mod tests {
 
     #[test]
     fn test_hash_uncompressed_constant() -> Result<()> {
-        check_hash_uncompressed::<32, 48>(Mode::Constant, 7239, 0, 0, 0)
+        check_hash_uncompressed::<32, 48>(Mode::Constant, 2, 0, 0, 0)
     }
 
     #[test]
@@ -164,7 +333,7 @@ mod tests {
     #[test]
     fn test_hash_uncompressed_bhp256_constant() -> Result<()> {
         let mut rng = TestRng::default();
-        check_hash_uncompressed!(BHP256, Constant, 261, (756, 0, 0, 0), &mut rng)
+        check_hash_uncompressed!(BHP256, Constant, 261, (2, 0, 0, 0), &mut rng)
     }
 
     #[test]
@@ -182,7 +351,7 @@ mod tests {
     #[test]
     fn test_hash_uncompressed_bhp512_constant() -> Result<()> {
         let mut rng = TestRng::default();
-        check_hash_uncompressed!(BHP512, Constant, 522, (1113, 0, 0, 0), &mut rng)
+        check_hash_uncompressed!(BHP512, Constant, 522, (2, 0, 0, 0), &mut rng)
     }
 
     #[test]
@@ -200,7 +369,7 @@ mod tests {
     #[test]
     fn test_hash_uncompressed_bhp768_constant() -> Result<()> {
         let mut rng = TestRng::default();
-        check_hash_uncompressed!(BHP768, Constant, 783, (1488, 0, 0, 0), &mut rng)
+        check_hash_uncompressed!(BHP768, Constant, 783, (2, 0, 0, 0), &mut rng)
     }
 
     #[test]
@@ -218,9 +387,9 @@ mod tests {
     #[test]
     fn test_hash_uncompressed_bhp1024_constant() -> Result<()> {
         let mut rng = TestRng::default();
-        check_hash_uncompressed!(BHP1024, Constant, 1043, (1815, 0, 0, 0), &mut rng)?;
-        check_hash_uncompressed!(BHP1024, Constant, 1044, (1815, 0, 0, 0), &mut rng)?;
-        check_hash_uncompressed!(BHP1024, Constant, 1046, (2413, 0, 0, 0), &mut rng)
+        check_hash_uncompressed!(BHP1024, Constant, 1043, (2, 0, 0, 0), &mut rng)?;
+        check_hash_uncompressed!(BHP1024, Constant, 1044, (2, 0, 0, 0), &mut rng)?;
+        check_hash_uncompressed!(BHP1024, Constant, 1046, (2, 0, 0, 0), &mut rng)
     }
 
     #[test]
@@ -248,4 +417,125 @@ mod tests {
         check_hash_uncompressed!(BHP768, Private, 512, (423, 0, 900, 900), &mut rng)?;
         check_hash_uncompressed!(BHP1024, Private, 512, (407, 0, 875, 875), &mut rng)
     }
+
+    #[test]
+    fn test_hash_uncompressed_coordinates() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native);
+
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for i in 0..ITERATIONS {
+                // Sample a random input.
+                let input = (0..522).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+                let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+                // Perform the hash operations.
+                let digest = circuit.hash(&circuit_input);
+                let candidate = circuit.hash_uncompressed(&circuit_input);
+
+                Circuit::scope(format!("BHP {mode} {i}"), || {
+                    // Extract the coordinates, which is free.
+                    let x = candidate.to_x_coordinate();
+                    let y = candidate.to_y_coordinate();
+                    assert_scope!(0, 0, 0, 0);
+
+                    // Ensure the x-coordinate is the hash.
+                    assert_eq!(digest.eject_value(), x.eject_value());
+
+                    // Ensure the coordinates satisfy the twisted Edwards curve equation: a * x^2 + y^2 = 1 + d * x^2 * y^2.
+                    let a = Field::constant(console::Group::<<Circuit as Environment>::Network>::EDWARDS_A);
+                    let d = Field::constant(console::Group::<<Circuit as Environment>::Network>::EDWARDS_D);
+                    let (x2, y2) = (x.square(), y.square());
+                    let lhs = &(&a * &x2) + &y2;
+                    let rhs = Field::one() + &(&d * &(&x2 * &y2));
+                    assert!(lhs.is_equal(&rhs).eject_value());
+                    assert!(Circuit::is_satisfied_in_scope());
+                });
+                Circuit::reset();
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_uncompressed_affine_matches_native() -> Result<()> {
+        use console::HashUncompressed as H;
+
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for num_bits in [1, 256, 522, 1044] {
+                // Sample a random input.
+                let input = (0..num_bits).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+                let expected = native.hash_uncompressed(&input)?;
+                let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+                // Ensure the affine coordinates of the output match the native affine coordinates.
+                let (x, y) = circuit.hash_uncompressed(&circuit_input).to_xy_coordinates();
+                assert_eq!(expected.to_x_coordinate(), x.eject_value());
+                assert_eq!(expected.to_y_coordinate(), y.eject_value());
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_input_bits_per_iteration() {
+        // Ensure the BHP variants compute the expected sizes.
+        assert_eq!(Ok(261), BHP256::<Circuit>::max_input_bits_per_iteration());
+        assert_eq!(Ok(522), BHP512::<Circuit>::max_input_bits_per_iteration());
+        assert_eq!(Ok(783), BHP768::<Circuit>::max_input_bits_per_iteration());
+        assert_eq!(Ok(1044), BHP1024::<Circuit>::max_input_bits_per_iteration());
+        // Ensure the largest const generics do not overflow.
+        assert_eq!(Ok(255 * 255 * 3 - 252), BHP::<Circuit, 255, 255>::max_input_bits_per_iteration());
+
+        // Ensure parameters that cannot fit the data bits are an error, instead of wrapping.
+        let expected = BHPError::InvalidParameters { num_windows: 1, window_size: 8, num_data_bits: 252 };
+        assert_eq!(Err(expected.clone()), BHP::<Circuit, 1, 8>::max_input_bits_per_iteration());
+        assert_eq!(Err(expected), checked_max_input_bits_per_iteration(1, 8, 252));
+        assert!(checked_max_input_bits_per_iteration(28, 3, 252).is_err());
+
+        // Ensure deliberately huge sizes are an overflow error, instead of a silently-wrong small number.
+        let expected = BHPError::InvalidParameters { num_windows: usize::MAX, window_size: 2, num_data_bits: 252 };
+        assert_eq!(Err(expected), checked_max_input_bits_per_iteration(usize::MAX, 2, 252));
+        assert!(checked_max_input_bits_per_iteration(usize::MAX / 3 + 1, 1, 252).is_err());
+    }
+
+    #[test]
+    fn test_hash_uncompressed_with_window_indices() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native);
+
+        let mut rng = TestRng::default();
+
+        // BHP512 has 6 windows of 129 bits, and each preimage begins with 252 data bits.
+        for (num_input_bits, expected) in [
+            // The preimage has 260 bits, which consumes the first 3 windows.
+            (8, vec![vec![0, 1, 2]]),
+            // The preimage has 516 bits, which consumes the first 4 windows.
+            (264, vec![vec![0, 1, 2, 3]]),
+            // The preimage has 774 bits, which consumes all 6 windows.
+            (522, vec![vec![0, 1, 2, 3, 4, 5]]),
+            // The second preimage has 253 bits, which consumes the first 2 windows.
+            (523, vec![vec![0, 1, 2, 3, 4, 5], vec![0, 1]]),
+        ] {
+            let input: Vec<Boolean<_>> =
+                Inject::new(Mode::Private, (0..num_input_bits).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>());
+            let (candidate, indices) = circuit.hash_uncompressed_with_window_indices(&input);
+            assert_eq!(expected, indices, "{num_input_bits} bits");
+            assert_eq!(circuit.hash_uncompressed(&input).eject_value(), candidate.eject_value());
+            Circuit::reset();
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,134 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the BHP hash of the given input as a field element, prefixed with the given version.
+    ///
+    /// The preimage is `VERSION || INPUT`, where `VERSION` is the 8 little-endian bits of the constant version.
+    /// This separates the digests of different preimage formats, so a digest of one version can never
+    /// be mistaken for a digest of another version of the same input.
+    pub fn hash_versioned(&self, version: u8, input: &[Boolean<E>]) -> Field<E> {
+        // Initialize a vector for the hash preimage.
+        let mut preimage = Vec::with_capacity(8 + input.len());
+        // Prepend the input with the version.
+        U8::constant(console::U8::new(version)).write_bits_le(&mut preimage);
+        preimage.extend_from_slice(input);
+        // Hash the preimage.
+        self.hash_field(&preimage)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+    const DOMAIN: &str = "BHPCircuit0";
+
+    type NativeField = console::Field<<Circuit as Environment>::Network>;
+
+    /// Returns the native BHP hash of the given input, prefixed with the given version.
+    fn native_hash_versioned(
+        native: &console::BHP512<<Circuit as Environment>::Network>,
+        version: u8,
+        input: &[bool],
+    ) -> Result<NativeField> {
+        use console::{Hash as H, ToBits as TB};
+
+        let mut preimage = console::U8::<<Circuit as Environment>::Network>::new(version).to_bits_le();
+        preimage.extend_from_slice(input);
+        native.hash(&preimage)
+    }
+
+    fn check_hash_versioned(
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random version and input.
+            let version = u8::rand(&mut rng);
+            let input = (0..256).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+            // Compute the expected digest.
+            let expected = native_hash_versioned(&native, version, &input)?;
+            // Prepare the circuit input.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+            Circuit::scope(format!("BHP {mode} {i}"), || {
+                let candidate = circuit.hash_versioned(version, &circuit_input);
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+                assert_eq!(expected, candidate.eject_value());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_versioned_constant() -> Result<()> {
+        check_hash_versioned(Mode::Constant, 10, 0, 0, 0)
+    }
+
+    #[test]
+    fn test_hash_versioned_public() -> Result<()> {
+        check_hash_versioned(Mode::Public, 425, 0, 436, 436)
+    }
+
+    #[test]
+    fn test_hash_versioned_private() -> Result<()> {
+        check_hash_versioned(Mode::Private, 425, 0, 436, 436)
+    }
+
+    #[test]
+    fn test_hash_versioned_distinct_versions() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Sample a random input.
+            let input = (0..256).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input.clone());
+
+            // Hash the same input with two versions.
+            let first = circuit.hash_versioned(0, &circuit_input);
+            let second = circuit.hash_versioned(1, &circuit_input);
+            assert_eq!(native_hash_versioned(&native, 0, &input)?, first.eject_value());
+            assert_eq!(native_hash_versioned(&native, 1, &input)?, second.eject_value());
+
+            // Ensure the versions are separated from each other, and from the unversioned hash.
+            assert_ne!(first.eject_value(), second.eject_value());
+            assert_ne!(circuit.hash_field(&circuit_input).eject_value(), first.eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+}
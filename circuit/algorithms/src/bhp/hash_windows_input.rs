@@ -0,0 +1,107 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the BHP hash of the given per-window bit groups as a field element,
+    /// which equals the BHP hash of their concatenation.
+    ///
+    /// This method halts if a group exceeds `WINDOW_SIZE * BHP_CHUNK_SIZE` bits. The groups are written once
+    /// into the scratch buffer of the hash, which also holds the preimage of each iteration, without an intermediate
+    /// concatenation. Note that the preimage is prefixed with the domain and length, so the groups are not aligned
+    /// with the windows of the bases.
+    pub fn hash_windows_input(&self, windows: &[&[Boolean<E>]]) -> Field<E> {
+        // Determine the number of bits in each window.
+        let window_size_in_bits = WINDOW_SIZE as usize * BHP_CHUNK_SIZE;
+
+        // Ensure each group fits in a window.
+        if let Some((index, window)) = windows.iter().enumerate().find(|(_, window)| window.len() > window_size_in_bits)
+        {
+            E::halt(format!(
+                "Window {index} of the BHP input has {} bits, exceeding the window size of {window_size_in_bits} bits",
+                window.len()
+            ))
+        }
+
+        // Write the groups into the scratch buffer, with the exact capacity of the input.
+        let mut scratch = Vec::with_capacity(windows.iter().map(|window| window.len()).sum());
+        windows.iter().for_each(|window| scratch.extend_from_slice(window));
+        // Hash the input, writing the preimage of each iteration after it.
+        match self.try_hash_iterations_in_scratch(None, &mut scratch) {
+            Ok(digest) => digest.to_x_coordinate(),
+            Err(error) => E::halt(error.to_string()),
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "BHPCircuit0";
+
+    #[test]
+    fn test_hash_windows_input() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native);
+        Circuit::reset();
+
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Sample per-window groups of full, partial, and empty windows.
+            let windows = [129, 129, 50, 0, 129, 7]
+                .iter()
+                .map(|num_bits| {
+                    let bits = (0..*num_bits).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+                    Inject::new(mode, bits)
+                })
+                .collect::<Vec<Vec<Boolean<_>>>>();
+            let slices = windows.iter().map(|window| window.as_slice()).collect::<Vec<_>>();
+
+            // Ensure the per-window form equals hashing the concatenation, with the same number of constraints.
+            let expected = Circuit::scope("concatenation", || {
+                let expected = circuit.hash_field(windows.concat());
+                (expected.eject_value(), Circuit::count_in_scope())
+            });
+            let candidate = Circuit::scope("windows", || {
+                let candidate = circuit.hash_windows_input(&slices);
+                (candidate.eject_value(), Circuit::count_in_scope())
+            });
+            assert_eq!(expected, candidate, "(mode = {mode})");
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_windows_input_rejects_oversized_window() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native);
+
+        // Ensure a group exceeding the window size halts.
+        let window: Vec<Boolean<_>> = Inject::new(Mode::Private, vec![true; 130]);
+        let result = std::panic::catch_unwind(|| circuit.hash_windows_input(&[&window]));
+        assert!(result.is_err());
+        Circuit::reset();
+        Ok(())
+    }
+}
@@ -0,0 +1,261 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the BHP hash of the given data bits as a field element, with a trailing checksum window.
+    ///
+    /// The data bits are split into windows of `WINDOW_SIZE * BHP_CHUNK_SIZE` bits (with the last window
+    /// zero-extended), and the checksum window is the XOR of the data windows, computed in-circuit.
+    /// The checksum is aligned to a window of the bases in the preimage of the last iteration (which begins with
+    /// `DATA_BITS` bits of domain and length, or of the previous digest), so the input is
+    /// `[ DATA || 0...0 || CHECKSUM ]`, where the data is padded with `false` bits to the next window boundary,
+    /// or to the next iteration, if the checksum does not fit in the rest of the iteration.
+    ///
+    /// This method halts if the parameters do not fit an iteration, or if the checksum window does not fit
+    /// after the prefix of an iteration.
+    pub fn hash_with_checksum(&self, data_bits: &[Boolean<E>]) -> Field<E> {
+        // Determine the number of bits in each window.
+        let window_size_in_bits = WINDOW_SIZE as usize * BHP_CHUNK_SIZE;
+
+        // Compute the checksum window as the XOR of the data windows.
+        let mut checksum = vec![Boolean::constant(false); window_size_in_bits];
+        for window in data_bits.chunks(window_size_in_bits) {
+            checksum
+                .iter_mut()
+                .zip(window)
+                .for_each(|(checksum_bit, data_bit)| *checksum_bit = &*checksum_bit ^ data_bit);
+        }
+
+        // Returns the given position in the preimage of an iteration, rounded up to a window boundary.
+        let align = |position: usize| (position + window_size_in_bits - 1) / window_size_in_bits * window_size_in_bits;
+
+        // The number of data bits that prefix the preimage of each iteration.
+        let num_data_bits = E::BaseField::size_in_data_bits();
+        // The maximum number of input bits per iteration.
+        let max_input_bits_per_iteration = match Self::max_input_bits_per_iteration() {
+            Ok(max_input_bits_per_iteration) => max_input_bits_per_iteration,
+            Err(error) => E::halt(error.to_string()),
+        };
+        // The number of bits in the preimage of each iteration, including its prefix.
+        let num_iteration_bits = num_data_bits + max_input_bits_per_iteration;
+
+        // Ensure the checksum window fits after the prefix of an iteration.
+        if align(num_data_bits) + window_size_in_bits > num_iteration_bits {
+            E::halt(format!("A BHP checksum window does not fit in an iteration of {NUM_WINDOWS} windows"))
+        }
+
+        // Determine the position after the data in the preimage of its last iteration.
+        let mut position = num_data_bits + data_bits.len() % max_input_bits_per_iteration;
+        // Determine the number of padding bits, to the next window boundary, or to the next iteration.
+        let num_padding_bits = match align(position) + window_size_in_bits > num_iteration_bits {
+            true => {
+                let num_padding_bits = num_iteration_bits - position;
+                position = num_data_bits;
+                num_padding_bits + align(position) - position
+            }
+            false => align(position) - position,
+        };
+
+        // Initialize the scratch buffer for the input, as the data, padding, and checksum.
+        let mut scratch = Vec::with_capacity(data_bits.len() + num_padding_bits + window_size_in_bits);
+        scratch.extend_from_slice(data_bits);
+        scratch.resize(data_bits.len() + num_padding_bits, Boolean::constant(false));
+        scratch.extend(checksum);
+
+        // Hash the input, writing the preimage of each iteration after it.
+        match self.try_hash_iterations_in_scratch(None, &mut scratch) {
+            Ok(digest) => digest.to_x_coordinate(),
+            Err(error) => E::halt(error.to_string()),
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+    const DOMAIN: &str = "BHPCircuit0";
+
+    type NativeField = console::Field<<Circuit as Environment>::Network>;
+
+    /// Returns the checksum window of the given data bits, as the XOR of the data windows.
+    fn native_checksum(data_bits: &[bool]) -> Vec<bool> {
+        let window_size_in_bits = 43 * BHP_CHUNK_SIZE;
+
+        let mut checksum = vec![false; window_size_in_bits];
+        for window in data_bits.chunks(window_size_in_bits) {
+            checksum.iter_mut().zip(window).for_each(|(checksum_bit, data_bit)| *checksum_bit ^= data_bit);
+        }
+        checksum
+    }
+
+    /// Returns the native BHP512 input of the given 300 data bits, followed by the given checksum window.
+    ///
+    /// Each iteration of BHP512 has 6 windows of 129 bits, and begins with a prefix of 252 bits. So, 300 data bits
+    /// end at bit 552 of the first iteration, and are padded with 93 bits to bit 645, where the checksum occupies
+    /// the last window of the iteration.
+    fn native_input_with_checksum(data_bits: &[bool], checksum: &[bool]) -> Vec<bool> {
+        assert_eq!(300, data_bits.len());
+        [data_bits, &[false; 93], checksum].concat()
+    }
+
+    /// Returns the native BHP hash of the given 300 data bits, followed by the given checksum window.
+    fn native_hash_with_checksum(
+        native: &console::BHP512<<Circuit as Environment>::Network>,
+        data_bits: &[bool],
+        checksum: &[bool],
+    ) -> Result<NativeField> {
+        use console::Hash as H;
+        native.hash(&native_input_with_checksum(data_bits, checksum))
+    }
+
+    fn check_hash_with_checksum(
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample random data bits spanning three windows, with a partial last window.
+            let data_bits = (0..300).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+            // Compute the expected digest.
+            let expected = native_hash_with_checksum(&native, &data_bits, &native_checksum(&data_bits))?;
+            // Prepare the circuit data bits.
+            let circuit_data_bits: Vec<Boolean<_>> = Inject::new(mode, data_bits);
+
+            Circuit::scope(format!("BHP {mode} {i}"), || {
+                let candidate = circuit.hash_with_checksum(&circuit_data_bits);
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+                assert_eq!(expected, candidate.eject_value());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_with_checksum_constant() -> Result<()> {
+        check_hash_with_checksum(Mode::Constant, 2, 0, 0, 0)
+    }
+
+    #[test]
+    fn test_hash_with_checksum_public() -> Result<()> {
+        check_hash_with_checksum(Mode::Public, 440, 0, 1004, 1004)
+    }
+
+    #[test]
+    fn test_hash_with_checksum_private() -> Result<()> {
+        check_hash_with_checksum(Mode::Private, 440, 0, 1004, 1004)
+    }
+
+    #[test]
+    fn test_hash_with_checksum_detects_corruption() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Sample random data bits, and corrupt one data bit without updating the checksum.
+            let data_bits = (0..300).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+            let checksum = native_checksum(&data_bits);
+            let mut corrupted = data_bits.clone();
+            corrupted[150] = !corrupted[150];
+
+            // Ensure the corrupted data with the stale checksum changes the digest.
+            let circuit_data_bits: Vec<Boolean<_>> = Inject::new(mode, data_bits);
+            let digest = circuit.hash_with_checksum(&circuit_data_bits).eject_value();
+            assert_ne!(digest, native_hash_with_checksum(&native, &corrupted, &checksum)?);
+
+            // Ensure the corrupted data is not accepted with its recomputed checksum either.
+            let circuit_corrupted: Vec<Boolean<_>> = Inject::new(mode, corrupted);
+            assert_ne!(digest, circuit.hash_with_checksum(&circuit_corrupted).eject_value());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_with_checksum_occupies_last_window() -> Result<()> {
+        use console::HashUncompressed as H;
+
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Public, Mode::Private] {
+            // Sample random data bits, and compute their checksum.
+            let data_bits = (0..300).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+            let checksum = native_checksum(&data_bits);
+
+            // Compute the uncompressed hash with the checksum, and with an all-false checksum.
+            let with_checksum = native.hash_uncompressed(&native_input_with_checksum(&data_bits, &checksum))?;
+            let without_checksum = native.hash_uncompressed(&native_input_with_checksum(&data_bits, &[false; 129]))?;
+
+            // Ensure the checksum only changes the contribution of the last window of the bases.
+            let checksum_window = circuit.hasher.hash_window(5, &Vec::<Boolean<_>>::constant(checksum.clone()));
+            let false_window = circuit.hasher.hash_window(5, &vec![Boolean::constant(false); 129]);
+            assert_eq!(with_checksum - without_checksum, (checksum_window - false_window).eject_value());
+
+            // Ensure the digest is the hash with the checksum.
+            let circuit_data_bits: Vec<Boolean<_>> = Inject::new(mode, data_bits);
+            let digest = circuit.hash_with_checksum(&circuit_data_bits);
+            assert_eq!(with_checksum.to_x_coordinate(), digest.eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_with_checksum_rejects_small_parameters() -> Result<()> {
+        /// Returns the message that the given logic halts with.
+        fn halt_message(logic: impl FnOnce() + std::panic::UnwindSafe) -> String {
+            let result = std::panic::catch_unwind(logic);
+            Circuit::reset();
+            result.err().and_then(|error| error.downcast_ref::<String>().cloned()).unwrap_or_default()
+        }
+
+        // Ensure parameters that do not fit an iteration halt with the error of the parameters.
+        let native = console::BHP::<<Circuit as Environment>::Network, 1, 43>::setup(DOMAIN)?;
+        let circuit = BHP::<Circuit, 1, 43>::constant(native);
+        let input = vec![Boolean::constant(true); 10];
+        let expected = BHPError::InvalidParameters { num_windows: 1, window_size: 43, num_data_bits: 252 };
+        assert_eq!(expected.to_string(), halt_message(|| drop(circuit.hash_with_checksum(&input))));
+
+        // Ensure parameters where the checksum window does not fit after the prefix halt.
+        let native = console::BHP::<<Circuit as Environment>::Network, 2, 43>::setup(DOMAIN)?;
+        let circuit = BHP::<Circuit, 2, 43>::constant(native);
+        let expected = "A BHP checksum window does not fit in an iteration of 2 windows";
+        assert_eq!(expected, halt_message(|| drop(circuit.hash_with_checksum(&input))));
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the BHP hash of the given input as a field element, along with the provenance of each window
+    /// in each iteration, as `true` if the window was constant-folded, and `false` if it was constrained.
+    ///
+    /// The provenance is recorded by the hasher, as it evaluates each window. A window is constant-folded
+    /// if all of its preimage bits are constant. The domain and length prefix of the first iteration is constant,
+    /// while the digest prefix of each subsequent iteration is constant only if every window of the preceding
+    /// iterations was constant-folded. If the input is entirely constant and is hashed by the native BHP,
+    /// no window is evaluated in the circuit, and the provenance is empty.
+    pub fn hash_with_metadata(&self, input: &[Boolean<E>]) -> (Field<E>, Vec<Vec<bool>>) {
+        // Hash the input in a context that records the windows it evaluates.
+        let context = BHPContext::with_trace();
+        let digest = match self.try_hash_iterations(Some(&context), input) {
+            Ok(digest) => digest.to_x_coordinate(),
+            Err(error) => E::halt(error.to_string()),
+        };
+        (digest, context.trace().map(|trace| trace.windows).unwrap_or_default())
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "BHPCircuit0";
+
+    #[test]
+    fn test_hash_with_metadata() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native);
+        Circuit::reset();
+
+        let mut rng = TestRng::default();
+
+        // Each iteration of BHP512 hashes a preimage of 252 prefix bits and 522 input bits, in windows of 129 bits.
+        // Private inputs in `135..264` only touch window 3 of the first iteration, in `522..528` only touch
+        // window 1 of the second iteration, and in `0..6` only touch window 1 of the first iteration.
+        // An entirely constant input is hashed natively, so no window is evaluated in the circuit.
+        for (private_range, num_iterations, expected) in [
+            (0..0, 1, vec![]),
+            (135..264, 1, vec![vec![true, true, true, false, true, true]]),
+            (0..6, 1, vec![vec![true, false, true, true, true, true]]),
+            (0..0, 2, vec![]),
+            (522..528, 2, vec![vec![true; 6], vec![true, false, true, true, true, true]]),
+            (135..264, 2, vec![vec![true, true, true, false, true, true], vec![false, false, true, true, true, true]]),
+        ] {
+            // Sample a random input, where the bits in the given range are private, and the rest are constant.
+            let num_bits = num_iterations * 522;
+            let input = (0..num_bits)
+                .map(|i| {
+                    let mode = if private_range.contains(&i) { Mode::Private } else { Mode::Constant };
+                    Boolean::new(mode, bool::rand(&mut rng))
+                })
+                .collect::<Vec<Boolean<_>>>();
+
+            // Ensure the provenance of each window is correct, and the digest matches the hash.
+            let (digest, provenance) = circuit.hash_with_metadata(&input);
+            assert_eq!(expected, provenance, "(private = {private_range:?})");
+            assert_eq!(circuit.hash_field(&input).eject_value(), digest.eject_value());
+
+            // Ensure the number of windows matches the windows consumed in each iteration, if any were evaluated.
+            if !provenance.is_empty() {
+                let (_, indices) = circuit.hash_uncompressed_with_window_indices(&input);
+                assert_eq!(
+                    indices.iter().map(Vec::len).collect::<Vec<_>>(),
+                    provenance.iter().map(Vec::len).collect::<Vec<_>>()
+                );
+            }
+
+            // Ensure a constant-folded hash is constant.
+            assert_eq!(provenance.iter().flatten().all(|window| *window), digest.is_constant());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the BHP hash of the given input as a field element, and `true` if the preimage of any iteration
+    /// was zero-padded to a multiple of `BHP_CHUNK_SIZE` bits, or `false` if every preimage was chunk-aligned.
+    ///
+    /// The flag depends only on the input length, so it is returned as a native `bool`, and costs no constraints.
+    pub fn hash_with_padding_flag(&self, input: &[Boolean<E>]) -> (Field<E>, bool) {
+        // The number of data bits in the output.
+        let num_data_bits = E::BaseField::size_in_data_bits();
+        // The maximum number of input bits per iteration.
+        let max_input_bits_per_iteration = match Self::max_input_bits_per_iteration() {
+            Ok(max_input_bits_per_iteration) => max_input_bits_per_iteration,
+            Err(error) => E::halt(error.to_string()),
+        };
+
+        // Each preimage is the domain and length prefix (or the previous digest) followed by a block of the input,
+        // so it is padded if the prefix and the block are not a multiple of `BHP_CHUNK_SIZE` bits.
+        let is_padded = input
+            .chunks(max_input_bits_per_iteration)
+            .any(|input_bits| (num_data_bits + input_bits.len()) % BHP_CHUNK_SIZE != 0);
+
+        (self.hash_field(input), is_padded)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "BHPCircuit0";
+
+    #[test]
+    fn test_hash_with_padding_flag() -> Result<()> {
+        use console::Hash as H;
+
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+
+        let mut rng = TestRng::default();
+
+        // The prefix of each preimage is 252 bits, so an input block is aligned if its length is a multiple of 3.
+        for (num_bits, expected_is_padded) in [(0, false), (255, false), (522, false), (256, true), (523, true)] {
+            let input = (0..num_bits).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+            let expected = native.hash(&input)?;
+
+            for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input.clone());
+                let (candidate, is_padded) = circuit.hash_with_padding_flag(&circuit_input);
+                assert_eq!(expected, candidate.eject_value(), "(mode = {mode}, num_bits = {num_bits})");
+                assert_eq!(expected_is_padded, is_padded, "(mode = {mode}, num_bits = {num_bits})");
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the BHP hash of the given input as a field element, where the padded preimage of each iteration
+    /// is written into the given scratch buffer (e.g. to avoid an allocation per hash in a loop).
+    ///
+    /// The output equals `hash_field`. The buffer is cleared before use, and its contents afterwards are unspecified.
+    /// If the input is entirely constant, it is hashed natively as in `hash_field`, and the buffer is not used.
+    pub fn hash_with_scratch(&self, input: &[Boolean<E>], scratch: &mut Vec<Boolean<E>>) -> Field<E> {
+        match self.try_hash_iterations_with_scratch(None, input, scratch) {
+            Ok(digest) => digest.to_x_coordinate(),
+            Err(error) => E::halt(error.to_string()),
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "BHPCircuit0";
+
+    #[test]
+    fn test_hash_with_scratch() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native);
+        Circuit::reset();
+
+        let mut rng = TestRng::default();
+
+        // Initialize the scratch buffer, and the number of times it has been allocated.
+        let mut scratch = Vec::new();
+        let mut num_allocations = 0;
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for num_bits in [0, 1, 256, 522, 523, 1044] {
+                // Sample a random input.
+                let input: Vec<Boolean<_>> =
+                    Inject::new(mode, (0..num_bits).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>());
+
+                // Ensure the hash with the scratch buffer matches the hash, with the same counts.
+                let (expected, expected_count) =
+                    Circuit::scope("BHP", || (circuit.hash_field(&input), Circuit::count_in_scope()));
+                let capacity = scratch.capacity();
+                let (candidate, candidate_count) = Circuit::scope("BHP scratch", || {
+                    (circuit.hash_with_scratch(&input, &mut scratch), Circuit::count_in_scope())
+                });
+                assert_eq!(expected.eject_value(), candidate.eject_value(), "(mode = {mode}, num_bits = {num_bits})");
+                assert_eq!(expected_count, candidate_count, "(mode = {mode}, num_bits = {num_bits})");
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+
+                // Track if the scratch buffer was reallocated.
+                if scratch.capacity() != capacity {
+                    num_allocations += 1;
+                }
+            }
+        }
+        // Ensure the scratch buffer was allocated once, and reused for every subsequent hash.
+        assert_eq!(1, num_allocations);
+        Ok(())
+    }
+}
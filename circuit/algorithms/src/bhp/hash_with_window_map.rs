@@ -0,0 +1,144 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the BHP hash of the given input as a field element, where the given function is applied to the point
+    /// of each window as `f(window_index, window_point)`, before the windows of an iteration are summed.
+    ///
+    /// The window index is the index of the window of bases within its iteration. If `f` is the identity,
+    /// the output equals `hash_field`. As the native BHP does not support the map, a constant input is hashed
+    /// in the circuit (with its windows constant-folded), instead of natively.
+    /// This is intended for research into BHP variants (e.g. per-window scaling), and is not a standard BHP hash.
+    pub fn hash_with_window_map(&self, input: &[Boolean<E>], f: impl Fn(usize, Group<E>) -> Group<E>) -> Field<E> {
+        match self.try_hash_iterations(Some(&BHPContext::with_window_map(&f)), input) {
+            Ok(digest) => digest.to_x_coordinate(),
+            Err(error) => E::halt(error.to_string()),
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "BHPCircuit0";
+
+    #[test]
+    fn test_hash_with_window_map_identity_matches_hash() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native);
+
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for num_bits in [0, 1, 256, 522, 523, 1044] {
+                // Sample a random input.
+                let input: Vec<Boolean<_>> =
+                    Inject::new(mode, (0..num_bits).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>());
+
+                // Ensure the identity map matches the hash.
+                let (expected, expected_counts) =
+                    Circuit::scope("hash_field", || (circuit.hash_field(&input), Circuit::count_in_scope()));
+                let (candidate, candidate_counts) = Circuit::scope("hash_with_window_map", || {
+                    (circuit.hash_with_window_map(&input, |_, point| point), Circuit::count_in_scope())
+                });
+                assert_eq!(expected.eject_value(), candidate.eject_value(), "(mode = {mode}, num_bits = {num_bits})");
+                // Ensure the identity map costs the same variables and constraints as the hash, except for a constant
+                // input, which is hashed in the circuit instead of natively, and still costs 0 constraints.
+                // Note: The constants differ, as the map shares one context across the iterations.
+                match input.iter().all(|bit| bit.is_constant()) {
+                    true => assert_eq!(0, candidate_counts.3),
+                    false => assert_eq!(
+                        (expected_counts.1, expected_counts.2, expected_counts.3, expected_counts.4),
+                        (candidate_counts.1, candidate_counts.2, candidate_counts.3, candidate_counts.4),
+                        "(mode = {mode}, num_bits = {num_bits})"
+                    ),
+                }
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_with_window_map_doubling_matches_native() -> Result<()> {
+        use console::HashUncompressed as H;
+
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Sample a random input within one iteration.
+            let input = (0..512).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+            // As the windows of one iteration are summed, doubling each window doubles the uncompressed hash.
+            let expected = native.hash_uncompressed(&input)?.double().to_x_coordinate();
+
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+            let candidate = circuit.hash_with_window_map(&circuit_input, |_, point| point.double());
+            assert_eq!(expected, candidate.eject_value(), "(mode = {mode})");
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+    #[test]
+    fn test_hash_with_window_map_borrows_local_state() -> Result<()> {
+        use console::HashUncompressed as H;
+        use core::cell::RefCell;
+
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Sample a random input within one iteration, and a random scalar.
+            let input = (0..512).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+            let scalar = console::Scalar::rand(&mut rng);
+            // As the windows of one iteration are summed, scaling each window scales the uncompressed hash.
+            let expected = (native.hash_uncompressed(&input)? * scalar).to_x_coordinate();
+
+            // Initialize the local state borrowed by the map.
+            let scalar = Scalar::<Circuit>::new(mode, scalar);
+            let indices = RefCell::new(Vec::new());
+
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+            let candidate = circuit.hash_with_window_map(&circuit_input, |index, point| {
+                indices.borrow_mut().push(index);
+                point * &scalar
+            });
+            assert_eq!(expected, candidate.eject_value(), "(mode = {mode})");
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+
+            // Ensure the map was applied to each window of the iteration, in order.
+            let indices = indices.into_inner();
+            assert!(!indices.is_empty());
+            assert_eq!((0..indices.len()).collect::<Vec<_>>(), indices);
+        }
+        Ok(())
+    }
+}
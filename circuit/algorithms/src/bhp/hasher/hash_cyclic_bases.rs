@@ -0,0 +1,61 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[cfg(console)]
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPHasher<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the BHP hash of the given input as an affine group element, where the input may exceed
+    /// `NUM_WINDOWS` windows by reusing the bases cyclically. The windows in the `c`-th cycle of the bases
+    /// are hashed with the bases, and scaled by the constant offset `c + 1`, so the first cycle matches
+    /// `hash_uncompressed`. No bases are stored beyond those of the hasher.
+    ///
+    /// Note: As the windows of each cycle reuse the bases at a known offset, this does *not* provide
+    /// the collision resistance of independent bases. For example, a window of the second cycle contributes
+    /// twice the point of the same window in the first cycle, so an input which moves a chunk between cycles,
+    /// while rescaling the chunks it moves, collides with the original input. This mode is only suitable for
+    /// parameter-constrained deployments that accept this caveat.
+    pub(crate) fn hash_uncompressed_cyclic_bases(&self, input: &[Boolean<E>]) -> Group<E> {
+        // Ensure the input size is at least one window.
+        if let Err(error @ BHPError::InputTooShort { .. }) = Self::check_input_size(input.len()) {
+            E::halt(error.to_string())
+        }
+
+        // Pad the input to a multiple of `BHP_CHUNK_SIZE` for hashing.
+        let input = Self::pad_input(input);
+
+        // Initialize the shared context.
+        let context = BHPContext::new();
+
+        // Hash each cycle of `NUM_WINDOWS` windows with the bases, scaled by the offset of the cycle.
+        input
+            .chunks(Self::MAX_BITS)
+            .enumerate()
+            .map(|(cycle, bits)| {
+                let group = Self::hash_windows(&context, &self.bases, bits);
+                match cycle {
+                    // The first cycle uses the bases, as in `hash_uncompressed`.
+                    0 => group,
+                    // Each subsequent cycle increments the offset of the bases by 1.
+                    _ => group * Self::cycle_offset(cycle),
+                }
+            })
+            .fold(Group::zero(), |acc, group| acc + group)
+    }
+
+    /// Returns the constant offset `cycle + 1` of the bases in the given cycle of `hash_uncompressed_cyclic_bases`.
+    pub(crate) fn cycle_offset(cycle: usize) -> Scalar<E> {
+        Scalar::constant(console::Scalar::from_field_lossy(&console::Field::from_u64(cycle as u64 + 1)))
+    }
+}
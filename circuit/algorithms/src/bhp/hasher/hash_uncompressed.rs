@@ -26,154 +26,410 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> HashUncompres
     ///
     /// This uncompressed variant of the BHP hash function is provided to support
     /// the BHP commitment scheme, as it is typically not used by applications.
+    ///
+    /// Note: The synthesized constraints depend only on the input length and the modes of the input bits.
+    /// The values of public and private bits are only read inside `witness!` closures, which assign
+    /// new variables but never decide which constraints are added. This ensures the circuit synthesized
+    /// for proving matches the circuit synthesized for key generation and verification.
+    ///
+    /// For a non-constant input of `num_chunks` chunks spanning `num_windows` windows, this method costs
+    /// `5 * num_chunks + 5 * num_windows - 6` constraints. Each chunk costs 2 constraints, each chunk after
+    /// the first in a window costs 3 more for the Montgomery addition, each window costs 2 for the conversion
+    /// to twisted Edwards, and each window after the first costs 6 for the group addition. If the input is
+    /// padded with 2 constant bits, the last chunk saves 1 constraint as its `bit_0 & bit_1` is constant.
+    /// If `WINDOW_SIZE == 1`, each window is looked up directly on the twisted Edwards curve for 2 constraints,
+    /// so the cost is `8 * num_windows - 6` constraints instead.
+    /// A window of only constant bits (e.g. all-false constant bits) costs 0 constraints, as its contribution
+    /// is a constant that is folded with the other constant windows before the group additions.
+    ///
+    /// As the digest is the sum of independent window contributions, the hash is additively homomorphic
+    /// across window boundaries: if `a` is window-aligned (i.e. `a.len()` is a multiple of `WINDOW_SIZE * BHP_CHUNK_SIZE`)
+    /// and spans `k` windows, then `hash_uncompressed(a || b)` equals `hash_uncompressed(a)` plus the hash of `b`
+    /// computed with the bases starting at window `k`. If `a` is not window-aligned, the last window of `a`
+    /// and the first chunks of `b` share a window sum, and this relationship does not hold.
     fn hash_uncompressed(&self, input: &[Self::Input]) -> Self::Output {
-        // Ensure the input size is at least the window size.
-        if input.len() <= Self::MIN_BITS {
-            E::halt(format!("Inputs to this BHP must be greater than {} bits", Self::MIN_BITS))
+        self.hash_uncompressed_in_context(&BHPContext::new(), input)
+    }
+}
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPHasher<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the BHP hash of the given input as an affine group element,
+    /// using the constants of the given shared context.
+    pub(crate) fn hash_uncompressed_in_context(&self, context: &BHPContext<'_, E>, input: &[Boolean<E>]) -> Group<E> {
+        match self.try_hash_uncompressed_in_context(context, input) {
+            Ok(digest) => digest,
+            Err(error) => E::halt(error.to_string()),
         }
+    }
 
-        // Ensure the input size is within the parameter size.
-        let input = match input.len() <= Self::MAX_BITS {
-            true => {
-                // Pad the input to a multiple of `BHP_CHUNK_SIZE` for hashing.
-                if input.len() % BHP_CHUNK_SIZE != 0 {
-                    // Compute the number of padding bits.
-                    let padding = BHP_CHUNK_SIZE - (input.len() % BHP_CHUNK_SIZE);
-                    // Pad the input with `false` bits.
-                    let mut padded_input = Vec::with_capacity(input.len() + padding);
-                    padded_input.extend_from_slice(input);
-                    padded_input.resize(input.len() + padding, Boolean::constant(false));
-                    // Ensure the input is a multiple of `BHP_CHUNK_SIZE`.
-                    assert_eq!(padded_input.len() % BHP_CHUNK_SIZE, 0, "Input must be a multiple of {BHP_CHUNK_SIZE}");
-                    // Return the padded input.
-                    Cow::Owned(padded_input)
-                } else {
-                    // Return the input as a borrowed slice.
-                    Cow::Borrowed(input)
+    /// Returns the sum of the BHP hash of each window of the given input with its corresponding window of bases,
+    /// as an affine group element. The given input must be a multiple of `BHP_CHUNK_SIZE` in length,
+    /// and must not exceed the number of windows in the given bases.
+    pub(super) fn hash_windows(
+        context: &BHPContext<'_, E>,
+        bases: &[Vec<BaseLookups<E>>],
+        input: &[Boolean<E>],
+    ) -> Group<E> {
+        // Compute sum of h_i^{sum of (1-2*c_{i,j,2})*(1+c_{i,j,0}+2*c_{i,j,1})*2^{4*(j-1)} for all j in segment}
+        // for all i. Described in section 5.4.1.7 in the Zcash protocol specification.
+        //
+        // Note: The `2^{4*(j-1)}` factor is precomputed in the bases, where the base for the j-th chunk
+        // of a window is the window generator doubled `4*(j-1)` times, and its lookups are its first
+        // `BHP_LOOKUP_SIZE` multiples, indexed by the selector bits of the chunk.
+        //
+        // Note: `.zip()` is used here (as opposed to `.zip_eq()`) as the input can be less than
+        // `NUM_WINDOWS * WINDOW_SIZE * BHP_CHUNK_SIZE` in length, which is the parameter size here.
+        // Start a new iteration in the trace, if it is recorded.
+        context.record(|trace| trace.windows.push(Vec::new()));
+
+        let (constant_windows, windows) = input
+            .chunks(WINDOW_SIZE as usize * BHP_CHUNK_SIZE)
+            .zip(bases.iter())
+            .enumerate()
+            .map(|(index, (bits, bases))| {
+                // If each window is a single chunk, there is no Montgomery sum to accumulate,
+                // so the chunk is looked up directly on the twisted Edwards curve.
+                let group = match WINDOW_SIZE == 1 {
+                    true => Self::hash_single_chunk_window(context, bits, &bases[0]), // 2 constraints
+                    false => Self::hash_window_chunks(context, bits, bases),
+                };
+                // Record the provenance of the window, if the trace is recorded.
+                context.record(|trace| trace.windows.last_mut().into_iter().for_each(|w| w.push(group.is_constant())));
+                // Apply the window map of the context, if any.
+                match &context.window_map {
+                    Some(window_map) => window_map(index, group),
+                    None => group,
                 }
-            }
-            false => E::halt(format!("Inputs to this BHP cannot exceed {} bits", Self::MAX_BITS)),
-        };
+            })
+            // Partition the windows into the constant (e.g. all-false constant bits) and non-constant windows.
+            .partition::<Vec<_>, _>(|group| group.is_constant());
+
+        // Fold the constant windows natively, and then sum the non-constant windows onto it.
+        //
+        // Note: As the digest is a sum of the window contributions, the order of summation does not matter.
+        // Summing the constant windows first ensures they contribute a single constant (0 constraints),
+        // instead of each one costing a group addition with the non-constant accumulating sum.
+        let constant_sum = constant_windows.into_iter().fold(Group::zero(), |acc, group| acc + group); // 0 constraints
+        windows.into_iter().fold(constant_sum, |acc, group| acc + group) // 6 constraints
+    }
+}
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPHasher<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the BHP hash of the given input as an affine group element,
+    /// or a `BHPError` if the input size is invalid, instead of halting.
+    pub(crate) fn try_hash_uncompressed(&self, input: &[Boolean<E>]) -> Result<Group<E>, BHPError> {
+        self.try_hash_uncompressed_in_context(&BHPContext::new(), input)
+    }
+
+    /// Returns the BHP hash of the given input as an affine group element, using the constants
+    /// of the given shared context, or a `BHPError` if the input size is invalid, instead of halting.
+    pub(crate) fn try_hash_uncompressed_in_context(
+        &self,
+        context: &BHPContext<'_, E>,
+        input: &[Boolean<E>],
+    ) -> Result<Group<E>, BHPError> {
+        // Ensure the input size is within the allowed range.
+        Self::check_input_size(input.len())?;
+
+        // Pad the input to a multiple of `BHP_CHUNK_SIZE` for hashing.
+        let input = Self::pad_input(input);
+
+        // Hash the windows of the input with the bases.
+        Ok(Self::hash_windows(context, &self.bases, &input))
+    }
+
+    /// Returns the indices of the windows of bases consumed by hashing an input of the given number of bits.
+    ///
+    /// As each window of the input is zipped with a window of bases, an input shorter than `MAX_BITS`
+    /// consumes a contiguous prefix of the windows, starting from index `0`.
+    pub(crate) fn window_indices(&self, num_bits: usize) -> Vec<usize> {
+        (0..num_bits).step_by(WINDOW_SIZE as usize * BHP_CHUNK_SIZE).zip(0..self.bases.len()).map(|(_, i)| i).collect()
+    }
 
+    /// Returns the BHP hash of the given bits with the window of bases at the given index, as an affine group element.
+    ///
+    /// This method halts if the index is out of bounds, or if the bits are empty or exceed one window.
+    pub(crate) fn hash_window(&self, index: usize, bits: &[Boolean<E>]) -> Group<E> {
+        // Ensure the index is within the number of windows.
+        if index >= self.bases.len() {
+            E::halt(format!("The BHP window index {index} exceeds the {} windows", self.bases.len()))
+        }
+        // Ensure the bits are within one window.
+        let window_size_in_bits = WINDOW_SIZE as usize * BHP_CHUNK_SIZE;
+        if bits.is_empty() || bits.len() > window_size_in_bits {
+            E::halt(format!("A BHP window must be 1 to {window_size_in_bits} bits, found {} bits", bits.len()))
+        }
+
+        // Hash the window of bits with its window of bases.
+        Self::hash_windows(&BHPContext::new(), &self.bases[index..=index], &Self::pad_input(bits))
+    }
+
+    /// Returns the BHP hash of the given window of bits with the given window of base lookups,
+    /// as an affine group element, by summing the Montgomery point of each chunk.
+    fn hash_window_chunks(context: &BHPContext<'_, E>, bits: &[Boolean<E>], bases: &[BaseLookups<E>]) -> Group<E> {
         // Declare the 1 constant field element.
         let one = Field::one();
-        // Declare the 1/2 constant field element.
-        let one_half = Field::constant(console::Field::<E::Network>::half());
-
-        // Declare the constant coefficients A and B for the Montgomery curve.
-        let coeff_a = Field::constant(console::Group::<E::Network>::MONTGOMERY_A);
-        let coeff_b = Field::constant(console::Group::<E::Network>::MONTGOMERY_B);
-
-        // Implements the incomplete addition formulae of two Montgomery curve points.
-        let montgomery_add = |(this_x, this_y): (&Field<E>, &Field<E>), (that_x, that_y): (&Field<E>, &Field<E>)| {
-            // Construct `lambda` as a witness defined as:
-            // `lambda := (that_y - this_y) / (that_x - this_x)`
-            let lambda: Field<E> = witness!(|this_x, this_y, that_x, that_y| (that_y - this_y) / (that_x - this_x));
-
-            // Ensure `lambda` is correct by enforcing:
-            // `(that_x - this_x) * lambda == (that_y - this_y)`
-            E::enforce(|| (that_x - this_x, &lambda, that_y - this_y));
-
-            // Construct `sum_x` as a witness defined as:
-            // `sum_x := (B * lambda^2) - A - this_x - that_x`
-            let sum_x: Field<E> = witness!(|lambda, that_x, this_x, coeff_a, coeff_b| {
-                coeff_b * lambda.square() - coeff_a - this_x - that_x
-            });
+        // Retrieve the 1/2 constant field element.
+        let one_half = &context.one_half;
 
-            // Ensure `sum_x` is correct by enforcing:
-            // `(B * lambda) * lambda == (A + this_x + that_x + sum_x)`
-            E::enforce(|| (&coeff_b * &lambda, &lambda, &coeff_a + this_x + that_x + &sum_x));
+        // Initialize accumulating sum variables for the x- and y-coordinates.
+        let mut sum = None;
 
-            // Construct `sum_y` as a witness defined as:
-            // `sum_y := -(this_y + (lambda * (this_x - sum_x)))`
-            let sum_y: Field<E> = witness!(|lambda, sum_x, this_x, this_y| -(this_y + (lambda * (sum_x - this_x))));
+        // One iteration costs 5 constraints.
+        bits.chunks(BHP_CHUNK_SIZE).zip(bases).for_each(|(chunk_bits, base_lookups)| {
+            // Record the chunk, if it has a non-constant bit.
+            if !chunk_bits.iter().all(|bit| bit.is_constant()) {
+                context.record(|trace| trace.chunks += 1);
+            }
 
-            // Ensure `sum_y` is correct by enforcing:
-            // `(this_x - sum_x) * lambda == (this_y + sum_y)`
-            E::enforce(|| (this_x - &sum_x, &lambda, this_y + &sum_y));
+            // Split the chunk into its selector bits, which index the lookups, and its sign bit.
+            let (selector_bits, sign_bit) = chunk_bits.split_at(BHP_CHUNK_SIZE - 1);
+            let selectors = Self::selector_products(selector_bits); // 1 constraint
+
+            // Compute the x-coordinate of the Montgomery curve point.
+            let montgomery_x = Self::interpolate(&base_lookups.0, &selectors);
+
+            // Compute the y-coordinate of the Montgomery curve point, without any negation.
+            let y = Self::interpolate(&base_lookups.1, &selectors);
+            // Determine the correct sign of the y-coordinate.
+            let montgomery_y = Self::conditionally_negate(&sign_bit[0], &y, one_half); // 1 constraint
+
+            // Update the accumulating sum, with a constraint-saving technique as follows:
+            match &sum {
+                // If `(sum_x, sum_y)` is `None`, then this is the first iteration,
+                // and we can save constraints by initializing `(sum_x, sum_y)` as
+                // `(montgomery_x, montgomery_y)` (instead of calling `montgomery_add`).
+                None => sum = Some((montgomery_x, montgomery_y)),
+                // Otherwise, call `montgomery_add` to add  to the accumulating sum.
+                Some((sum_x, sum_y)) => {
+                    // Sum the new Montgomery point into the accumulating sum (3 constraints).
+                    let (sum_x, sum_y) = Self::montgomery_add(context, (sum_x, sum_y), (&montgomery_x, &montgomery_y));
+                    // Record the Montgomery addition and its division for `lambda`, if they are not constant-folded.
+                    if !sum_x.is_constant() {
+                        context.record(|trace| {
+                            trace.montgomery_adds += 1;
+                            trace.field_inversions += 1;
+                        });
+                    }
+                    sum = Some((sum_x, sum_y));
+                }
+            }
+        });
 
-            (sum_x, sum_y)
-        };
+        // Convert the accumulating sum into the twisted Edwards point.
+        //
+        // Note: The accumulated sum of a window is never the identity, as it is `k * g` for the window
+        // generator `g` and a nonzero encoding `0 < |k| <= (p - 1) / 2` (each chunk encodes a nonzero value,
+        // including the all-zero chunk, and the window size is bounded in `setup`). As such, the Montgomery
+        // sum is always an affine point with `sum_y != 0` and `sum_x != -1`. The digest itself may be
+        // the identity, which is well-defined, as the windows are summed with complete Edwards addition.
+        match &sum {
+            Some((sum_x, sum_y)) => {
+                // Record the 2 divisions of the conversion, if they are not constant-folded.
+                if !sum_x.is_constant() {
+                    context.record(|trace| trace.field_inversions += 2);
+                }
+                // Convert the accumulated sum into a point on the twisted Edwards curve.
+                let edwards_x = sum_x.div_unchecked(sum_y); // 1 constraint (`sum_y` is never 0)
+                let edwards_y = (sum_x - &one).div_unchecked(&(sum_x + &one)); // 1 constraint (numerator & denominator are never both 0)
+                Group::from_xy_coordinates_unchecked(edwards_x, edwards_y) // 0 constraints (this is safe)
+            }
+            None => E::halt("Invalid iteration of BHP detected, a window was not evaluated"),
+        }
+    }
 
-        // Compute sum of h_i^{sum of (1-2*c_{i,j,2})*(1+c_{i,j,0}+2*c_{i,j,1})*2^{4*(j-1)} for all j in segment}
-        // for all i. Described in section 5.4.1.7 in the Zcash protocol specification.
+    /// Returns the BHP hash of the given chunk with the given base lookups, as an affine group element.
+    ///
+    /// This is the specialization of `hash_windows` for `WINDOW_SIZE == 1`, where each window is a single chunk.
+    /// As the chunk point is not summed with other chunks, the Montgomery form is skipped entirely:
+    /// the lookups are converted to twisted Edwards constants, and the chunk is looked up and conditionally
+    /// negated as `-(x, y) = (-x, y)`. This costs 2 constraints, instead of the 4 constraints of the general path
+    /// (which also converts the Montgomery point into a twisted Edwards point).
+    fn hash_single_chunk_window(
+        context: &BHPContext<'_, E>,
+        chunk_bits: &[Boolean<E>],
+        (x_bases, y_bases): &BaseLookups<E>,
+    ) -> Group<E> {
+        // Record the chunk, if it has a non-constant bit.
+        if !chunk_bits.iter().all(|bit| bit.is_constant()) {
+            context.record(|trace| trace.chunks += 1);
+        }
+
+        // Convert each lookup from a Montgomery point into a twisted Edwards point.
+        // Note: The lookups are constants, so the conversion allocates constants, and costs 0 constraints.
+        let one = Field::one();
+        let (edwards_x, edwards_y): (Vec<Field<E>>, Vec<Field<E>>) =
+            x_bases.iter().zip_eq(y_bases).map(|(x, y)| (x / y, (x - &one) / (x + &one))).unzip();
+
+        // Split the chunk into its selector bits, which index the lookups, and its sign bit.
+        let (selector_bits, sign_bit) = chunk_bits.split_at(BHP_CHUNK_SIZE - 1);
+        let selectors = Self::selector_products(selector_bits); // 1 constraint
+
+        // Compute the x-coordinate of the twisted Edwards point, without any negation.
+        let x = Self::interpolate(&edwards_x, &selectors);
+        // Compute the y-coordinate of the twisted Edwards point, which is unchanged by negation.
+        let y = Self::interpolate(&edwards_y, &selectors);
+
+        // Determine the correct sign of the x-coordinate.
+        let edwards_x = Self::conditionally_negate(&sign_bit[0], &x, &context.one_half); // 1 constraint
+
+        Group::from_xy_coordinates_unchecked(edwards_x, y) // 0 constraints (this is safe)
+    }
+
+    /// Returns the product of each subset of the given selector bits, indexed by the bitmask of the subset,
+    /// where the product of the empty subset is `None` (i.e. the constant `1`).
+    ///
+    /// The products are built incrementally, so each subset of 2 or more bits costs 1 constraint,
+    /// for a total of `2^k - k - 1` constraints for `k` selector bits (i.e. 1 constraint for a 3-bit chunk).
+    /// The products are shared by the interpolations of both coordinates of a lookup.
+    fn selector_products(selector_bits: &[Boolean<E>]) -> Vec<Option<Boolean<E>>> {
+        let mut products = Vec::with_capacity(1 << selector_bits.len());
+        products.push(None);
+        for bit in selector_bits {
+            for index in 0..products.len() {
+                let product = match &products[index] {
+                    None => bit.clone(),
+                    Some(product) => product & bit, // 1 constraint
+                };
+                products.push(Some(product));
+            }
+        }
+        products
+    }
+
+    /// Returns the lookup selected by the given selector products, as the multilinear interpolation
+    /// of the given lookups (i.e. `sum of c_S * prod_{i in S} b_i` over each subset `S` of the selector bits).
+    ///
+    /// The coefficients `c_S` are the constant lookups combined with alternating signs over the subsets of `S`
+    /// (e.g. `c_{0,1} = l_3 - l_2 - l_1 + l_0`), so the interpolation is a linear combination (0 constraints).
+    fn interpolate(lookups: &[Field<E>], selectors: &[Option<Boolean<E>>]) -> Field<E> {
+        // Ensure there is one lookup for each value of the selector bits.
+        debug_assert_eq!(lookups.len(), selectors.len(), "Each BHP chunk value must have one lookup");
+
+        // Compute the coefficient of each subset in place, one selector bit at a time.
+        let mut coefficients = lookups.to_vec();
+        let mut bit_mask = 1;
+        while bit_mask < coefficients.len() {
+            for index in (0..coefficients.len()).filter(|index| index & bit_mask != 0) {
+                coefficients[index] = &coefficients[index] - &coefficients[index ^ bit_mask];
+            }
+            bit_mask <<= 1;
+        }
+
+        // Sum each coefficient, scaled by the product of its subset of selector bits.
+        coefficients.into_iter().zip_eq(selectors).fold(Field::zero(), |sum, (coefficient, selector)| match selector {
+            None => sum + coefficient,
+            Some(selector) => sum + Field::from_boolean(selector) * coefficient,
+        })
+    }
+
+    /// Returns the given value, negated if the given sign bit is set.
+    ///
+    /// Instead of using `Field::ternary`, we create a witness & custom constraint to reduce
+    /// the number of nonzero entries in the circuit, improving setup & proving time for Varuna.
+    fn conditionally_negate(sign_bit: &Boolean<E>, value: &Field<E>, one_half: &Field<E>) -> Field<E> {
+        // Determine the correct sign of the value, as a witness.
+        let signed_value: Field<E> = witness!(|sign_bit, value| if sign_bit { -value } else { value });
+
+        // Ensure the conditional negation of `value` is correct as follows (1 constraint):
+        //     `(sign_bit - 1/2) * (-2 * value) == signed_value`
+        // which is equivalent to:
+        //     if `sign_bit == 0`, then `signed_value = -1/2 * -2 * value = value`
+        //     if `sign_bit == 1`, then `signed_value = 1/2 * -2 * value = -value`
         //
-        // Note: `.zip()` is used here (as opposed to `.zip_eq()`) as the input can be less than
-        // `NUM_WINDOWS * WINDOW_SIZE * BHP_CHUNK_SIZE` in length, which is the parameter size here.
-        input
-            .chunks(WINDOW_SIZE as usize * BHP_CHUNK_SIZE)
-            .zip(self.bases.iter())
-            .map(|(bits, bases)| {
-                // Initialize accumulating sum variables for the x- and y-coordinates.
-                let mut sum = None;
-
-                // One iteration costs 5 constraints.
-                bits.chunks(BHP_CHUNK_SIZE).zip(bases).for_each(|(chunk_bits, base_lookups)| {
-                    // Unzip the base lookups into `x_bases` and `y_bases`.
-                    let (x_bases, y_bases) = base_lookups;
-
-                    // Cast each input chunk bit as a field element.
-                    let bit_0 = Field::from_boolean(&chunk_bits[0]);
-                    let bit_1 = Field::from_boolean(&chunk_bits[1]);
-                    let bit_2 = Field::from_boolean(&chunk_bits[2]);
-                    let bit_0_and_1 = Field::from_boolean(&(&chunk_bits[0] & &chunk_bits[1])); // 1 constraint
-
-                    // Compute the x-coordinate of the Montgomery curve point.
-                    let montgomery_x: Field<E> = &x_bases[0]
-                        + &bit_0 * (&x_bases[1] - &x_bases[0])
-                        + &bit_1 * (&x_bases[2] - &x_bases[0])
-                        + &bit_0_and_1 * (&x_bases[3] - &x_bases[2] - &x_bases[1] + &x_bases[0]);
-
-                    // Compute the y-coordinate of the Montgomery curve point.
-                    let montgomery_y = {
-                        // Compute the y-coordinate of the Montgomery curve point, without any negation.
-                        let y: Field<E> = &y_bases[0]
-                            + bit_0 * (&y_bases[1] - &y_bases[0])
-                            + bit_1 * (&y_bases[2] - &y_bases[0])
-                            + bit_0_and_1 * (&y_bases[3] - &y_bases[2] - &y_bases[1] + &y_bases[0]);
-
-                        // Determine the correct sign of the y-coordinate, as a witness.
-                        //
-                        // Instead of using `Field::ternary`, we create a witness & custom constraint to reduce
-                        // the number of nonzero entries in the circuit, improving setup & proving time for Varuna.
-                        let montgomery_y: Field<E> = witness!(|chunk_bits, y| if chunk_bits[2] { -y } else { y });
-
-                        // Ensure the conditional negation of `witness_y` is correct as follows (1 constraint):
-                        //     `(bit_2 - 1/2) * (-2 * y) == montgomery_y`
-                        // which is equivalent to:
-                        //     if `bit_2 == 0`, then `montgomery_y = -1/2 * -2 * y = y`
-                        //     if `bit_2 == 1`, then `montgomery_y = 1/2 * -2 * y = -y`
-                        E::enforce(|| (-y.double(), bit_2 - &one_half, &montgomery_y)); // 1 constraint
-
-                        montgomery_y
-                    };
-
-                    // Update the accumulating sum, with a constraint-saving technique as follows:
-                    match &sum {
-                        // If `(sum_x, sum_y)` is `None`, then this is the first iteration,
-                        // and we can save constraints by initializing `(sum_x, sum_y)` as
-                        // `(montgomery_x, montgomery_y)` (instead of calling `montgomery_add`).
-                        None => sum = Some((montgomery_x, montgomery_y)),
-                        // Otherwise, call `montgomery_add` to add  to the accumulating sum.
-                        Some((sum_x, sum_y)) => {
-                            // Sum the new Montgomery point into the accumulating sum.
-                            sum = Some(montgomery_add((sum_x, sum_y), (&montgomery_x, &montgomery_y))); // 3 constraints
-                        }
-                    }
-                });
+        // Note: `value` is a linear combination, so `-value.double()` allocates no variables or
+        // constraints, and `value` is not doubled anywhere else, so there is nothing to share.
+        E::enforce(|| (-value.double(), Field::from_boolean(sign_bit) - one_half, &signed_value)); // 1 constraint
 
-                // Convert the accumulating sum into the twisted Edwards point.
-                match &sum {
-                    Some((sum_x, sum_y)) => {
-                        // Convert the accumulated sum into a point on the twisted Edwards curve.
-                        let edwards_x = sum_x.div_unchecked(sum_y); // 1 constraint (`sum_y` is never 0)
-                        let edwards_y = (sum_x - &one).div_unchecked(&(sum_x + &one)); // 1 constraint (numerator & denominator are never both 0)
-                        Group::from_xy_coordinates_unchecked(edwards_x, edwards_y) // 0 constraints (this is safe)
-                    }
-                    None => E::halt("Invalid iteration of BHP detected, a window was not evaluated"),
-                }
-            })
-            .fold(Group::zero(), |acc, group| acc + group) // 6 constraints
+        signed_value
+    }
+
+    /// Returns the sum of the two given Montgomery curve points, using the incomplete addition formulae.
+    ///
+    /// The formulae are undefined for points with equal x-coordinates (i.e. when doubling a point, or adding
+    /// a point to its negation), as the slope has a zero denominator. The accumulated points in `hash_windows`
+    /// are distinct multiples of the window generator, so this does not occur for valid bases, and otherwise
+    /// halts with a descriptive error when the witness is computed.
+    pub(super) fn montgomery_add(
+        context: &BHPContext<'_, E>,
+        (this_x, this_y): (&Field<E>, &Field<E>),
+        (that_x, that_y): (&Field<E>, &Field<E>),
+    ) -> (Field<E>, Field<E>) {
+        // Retrieve the constant coefficients A and B for the Montgomery curve.
+        let (coeff_a, coeff_b) = (&context.coeff_a, &context.coeff_b);
+
+        // Construct `lambda` as a witness defined as:
+        // `lambda := (that_y - this_y) / (that_x - this_x)`
+        let lambda: Field<E> = witness!(|this_x, this_y, that_x, that_y| {
+            // Ensure the denominator is nonzero.
+            if this_x == that_x {
+                E::halt(format!(
+                    "BHP Montgomery addition is undefined for points with equal x-coordinates (x = {this_x})"
+                ))
+            }
+            (that_y - this_y) / (that_x - this_x)
+        });
+
+        // Ensure `lambda` is correct by enforcing:
+        // `(that_x - this_x) * lambda == (that_y - this_y)`
+        E::enforce(|| (that_x - this_x, &lambda, that_y - this_y));
+
+        // Construct `sum_x` as a witness defined as:
+        // `sum_x := (B * lambda^2) - A - this_x - that_x`
+        let sum_x: Field<E> = witness!(|lambda, that_x, this_x, coeff_a, coeff_b| {
+            coeff_b * lambda.square() - coeff_a - this_x - that_x
+        });
+
+        // Ensure `sum_x` is correct by enforcing:
+        // `(B * lambda) * lambda == (A + this_x + that_x + sum_x)`
+        E::enforce(|| (coeff_b * &lambda, &lambda, coeff_a + this_x + that_x + &sum_x));
+
+        // Construct `sum_y` as a witness defined as:
+        // `sum_y := -(this_y + (lambda * (this_x - sum_x)))`
+        let sum_y: Field<E> = witness!(|lambda, sum_x, this_x, this_y| -(this_y + (lambda * (sum_x - this_x))));
+
+        // Ensure `sum_y` is correct by enforcing:
+        // `(this_x - sum_x) * lambda == (this_y + sum_y)`
+        E::enforce(|| (this_x - &sum_x, &lambda, this_y + &sum_y));
+
+        (sum_x, sum_y)
+    }
+
+    /// Returns the given input padded with `false` bits to a multiple of `BHP_CHUNK_SIZE`.
+    ///
+    /// If padding is required, the padded input is allocated once with its exact padded length,
+    /// so that padding never reallocates. Otherwise, the input is returned as a borrowed slice.
+    pub(super) fn pad_input(input: &[Boolean<E>]) -> Cow<'_, [Boolean<E>]> {
+        match input.len() % BHP_CHUNK_SIZE {
+            // Return the input as a borrowed slice.
+            0 => Cow::Borrowed(input),
+            remainder => {
+                // Compute the padded length.
+                let padded_len = input.len() + (BHP_CHUNK_SIZE - remainder);
+                // Pad the input with `false` bits.
+                let mut padded_input = Vec::with_capacity(padded_len);
+                padded_input.extend_from_slice(input);
+                padded_input.resize(padded_len, Boolean::constant(false));
+                // Ensure the input is a multiple of `BHP_CHUNK_SIZE`.
+                assert_eq!(padded_input.len() % BHP_CHUNK_SIZE, 0, "Input must be a multiple of {BHP_CHUNK_SIZE}");
+                // Return the padded input.
+                Cow::Owned(padded_input)
+            }
+        }
+    }
+
+    /// Returns an error if the given number of input bits is not within `(MIN_BITS, MAX_BITS]`.
+    pub(super) fn check_input_size(num_bits: usize) -> Result<(), BHPError> {
+        // Ensure the input size is at least the window size.
+        if num_bits <= Self::MIN_BITS {
+            let num_missing_bits = Self::MIN_BITS + 1 - num_bits;
+            return Err(BHPError::InputTooShort { num_bits, min_bits: Self::MIN_BITS, num_missing_bits });
+        }
+        // Ensure the input size is within the parameter size.
+        if num_bits > Self::MAX_BITS {
+            return Err(BHPError::InputTooLong { num_bits, max_bits: Self::MAX_BITS });
+        }
+        Ok(())
     }
 }
 
@@ -232,6 +488,401 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_hash_uncompressed_reports_missing_bits() -> Result<()> {
+        // Initialize the circuit BHP hasher.
+        let primitive = console::BHP::<<Circuit as Environment>::Network, 32, 48>::setup(MESSAGE)?;
+        let circuit = BHPHasher::<Circuit, 32, 48>::new(Mode::Constant, primitive);
+
+        type Hasher = BHPHasher<Circuit, 32, 48>;
+
+        // Construct an input that is one bit under the minimum accepted size of `MIN_BITS + 1` bits.
+        let input: Vec<Boolean<_>> = Inject::new(Mode::Private, vec![false; Hasher::MIN_BITS]);
+
+        // Ensure the error variant reports the exact deficit.
+        match circuit.try_hash_uncompressed(&input) {
+            Err(BHPError::InputTooShort { num_missing_bits, .. }) => assert_eq!(1, num_missing_bits),
+            _ => panic!("Expected the input to be reported as too short"),
+        }
+
+        // Ensure the halting message reports the exact deficit.
+        let result = std::panic::catch_unwind(|| circuit.hash_uncompressed(&input));
+        let message = result.err().and_then(|error| error.downcast_ref::<String>().cloned()).unwrap_or_default();
+        assert!(message.contains("found 144 bits (1 more required)"), "{message}");
+        Circuit::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_uncompressed_with_native_bases() -> Result<()> {
+        use console::HashUncompressed as H;
+
+        // Initialize the native BHP hasher.
+        let native = console::bhp::hasher::BHPHasher::<<Circuit as Environment>::Network, 32, 48>::setup(MESSAGE)?;
+        // Initialize the circuit BHP hasher with the exact bases of the native BHP hasher.
+        let circuit = BHPHasher::<Circuit, 32, 48>::from_native(&native);
+        // Determine the number of inputs.
+        let num_input_bits = 32 * 48 * BHP_CHUNK_SIZE;
+
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for i in 0..ITERATIONS {
+                // Sample a random input.
+                let input = (0..num_input_bits).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+                // Compute the expected hash.
+                let expected = native.hash_uncompressed(&input)?;
+                // Prepare the circuit input.
+                let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+                Circuit::scope(format!("BHP {mode} {i}"), || {
+                    // Perform the hash operation.
+                    let candidate = circuit.hash_uncompressed(&circuit_input);
+                    assert_eq!(expected, candidate.eject_value());
+                });
+                Circuit::reset();
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_uncompressed_is_independent_of_witness_values() -> Result<()> {
+        // Initialize the circuit BHP hasher.
+        let primitive = console::BHP::<<Circuit as Environment>::Network, 32, 48>::setup(MESSAGE)?;
+        let circuit = BHPHasher::<Circuit, 32, 48>::new(Mode::Constant, primitive);
+        // Determine the number of inputs.
+        let num_input_bits = 32 * 48 * BHP_CHUNK_SIZE;
+
+        let mut rng = TestRng::default();
+
+        // Synthesizes the hash circuit for the given input, and returns the resulting assignment.
+        let synthesize = |mode: Mode, input: Vec<bool>| {
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+            let _candidate = circuit.hash_uncompressed(&circuit_input);
+            assert!(Circuit::is_satisfied());
+            Circuit::eject_assignment_and_reset()
+        };
+
+        for mode in [Mode::Public, Mode::Private] {
+            // Synthesize the circuit with a random witness, as a prover would.
+            let prover = synthesize(mode, (0..num_input_bits).map(|_| bool::rand(&mut rng)).collect());
+            // Synthesize the circuit with a default witness, as done for key generation and verification.
+            let verifier = synthesize(mode, vec![false; num_input_bits]);
+
+            // Ensure the constraint systems are identical, ignoring the assigned values.
+            assert_eq!(prover.num_public(), verifier.num_public());
+            assert_eq!(prover.num_private(), verifier.num_private());
+            assert_eq!(prover.num_constraints(), verifier.num_constraints());
+            assert_eq!(prover.num_nonzeros(), verifier.num_nonzeros());
+            for ((a, b, c), (x, y, z)) in prover.constraints().iter().zip_eq(verifier.constraints()) {
+                for (first, second) in [(a, x), (b, y), (c, z)] {
+                    assert_eq!(first.constant(), second.constant());
+                    assert_eq!(first.terms(), second.terms());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_uncompressed_padding_matches_explicit_zero_bits() -> Result<()> {
+        // Initialize the circuit BHP hasher.
+        let primitive = console::BHP::<<Circuit as Environment>::Network, 32, 48>::setup(MESSAGE)?;
+        let circuit = BHPHasher::<Circuit, 32, 48>::new(Mode::Constant, primitive);
+        // Determine the number of inputs.
+        let num_input_bits = 32 * 48 * BHP_CHUNK_SIZE;
+
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Check inputs that are 1 and 2 bits short of a multiple of `BHP_CHUNK_SIZE`.
+            for num_padding_bits in [1, 2] {
+                // Sample a random input.
+                let input = (0..num_input_bits - num_padding_bits).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+                let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+                assert_eq!(num_padding_bits, BHP_CHUNK_SIZE - circuit_input.len() % BHP_CHUNK_SIZE);
+
+                // Explicitly append the zero bits that the hasher would otherwise pad internally.
+                let mut padded_input = circuit_input.clone();
+                padded_input.resize(num_input_bits, Boolean::constant(false));
+
+                Circuit::scope(format!("BHP {mode} {num_padding_bits}"), || {
+                    let expected = circuit.hash_uncompressed(&circuit_input);
+                    let candidate = circuit.hash_uncompressed(&padded_input);
+                    assert_eq!(expected.eject_value(), candidate.eject_value());
+                    assert!(Circuit::is_satisfied_in_scope());
+                });
+                Circuit::reset();
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_uncompressed_padding_for_every_residue() -> Result<()> {
+        use console::HashUncompressed as H;
+
+        type Hasher = BHPHasher<Circuit, 32, 48>;
+
+        // Initialize the native BHP hasher.
+        let native = console::bhp::hasher::BHPHasher::<<Circuit as Environment>::Network, 32, 48>::setup(MESSAGE)?;
+        // Initialize the circuit BHP hasher with the exact bases of the native BHP hasher.
+        let circuit = Hasher::from_native(&native);
+
+        let mut rng = TestRng::default();
+
+        for residue in 1..BHP_CHUNK_SIZE {
+            // Select the smallest, a middle, and the largest input size with the given residue.
+            let smallest = (Hasher::MIN_BITS + 1..).find(|n| n % BHP_CHUNK_SIZE == residue).unwrap();
+            let largest = (0..=Hasher::MAX_BITS).rev().find(|n| n % BHP_CHUNK_SIZE == residue).unwrap();
+            let middle = (smallest + largest) / 2 / BHP_CHUNK_SIZE * BHP_CHUNK_SIZE + residue;
+
+            for num_input_bits in [smallest, middle, largest] {
+                assert_eq!(residue, num_input_bits % BHP_CHUNK_SIZE);
+
+                for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                    // Sample a random input.
+                    let input = (0..num_input_bits).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+                    // Compute the expected hash.
+                    let expected = native.hash_uncompressed(&input)?;
+                    // Prepare the circuit input.
+                    let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+                    Circuit::scope(format!("BHP {mode} {num_input_bits}"), || {
+                        // Perform the hash operation.
+                        let candidate = circuit.hash_uncompressed(&circuit_input);
+                        assert_eq!(expected, candidate.eject_value());
+                        assert!(Circuit::is_satisfied_in_scope());
+                    });
+                    Circuit::reset();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_uncompressed_all_zero_input() -> Result<()> {
+        use console::HashUncompressed as H;
+
+        // Initialize the native BHP hasher.
+        let native = console::bhp::hasher::BHPHasher::<<Circuit as Environment>::Network, 32, 48>::setup(MESSAGE)?;
+        // Initialize the circuit BHP hasher with the exact bases of the native BHP hasher.
+        let circuit = BHPHasher::<Circuit, 32, 48>::from_native(&native);
+
+        for num_input_bits in [BHPHasher::<Circuit, 32, 48>::MIN_BITS + 1, BHPHasher::<Circuit, 32, 48>::MAX_BITS] {
+            // Compute the expected hash of the all-zero input.
+            let input = vec![false; num_input_bits];
+            let expected = native.hash_uncompressed(&input)?;
+            assert_ne!(console::Group::zero(), expected);
+
+            for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                // Ensure the circuit hash of the all-zero input is well-defined, and matches the native hash.
+                let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input.clone());
+                let candidate = circuit.hash_uncompressed(&circuit_input);
+                assert_eq!(expected, candidate.eject_value());
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_pad_input_allocates_once() {
+        type Hasher = BHPHasher<Circuit, 32, 48>;
+
+        for num_input_bits in [Hasher::MIN_BITS + 1, Hasher::MIN_BITS + 2, Hasher::MIN_BITS + 3, Hasher::MAX_BITS - 1] {
+            let input = vec![Boolean::<Circuit>::new(Mode::Private, true); num_input_bits];
+            let padded_len = (num_input_bits + BHP_CHUNK_SIZE - 1) / BHP_CHUNK_SIZE * BHP_CHUNK_SIZE;
+
+            match Hasher::pad_input(&input) {
+                // Ensure an input that is a multiple of `BHP_CHUNK_SIZE` is not copied.
+                Cow::Borrowed(candidate) => assert_eq!(padded_len, candidate.len()),
+                // Ensure the padded input is allocated with exactly its padded length.
+                Cow::Owned(candidate) => {
+                    assert_ne!(num_input_bits, padded_len);
+                    assert_eq!(padded_len, candidate.len());
+                    assert_eq!(padded_len, candidate.capacity());
+                    // Ensure the input is unchanged, and the padding is constant `false` bits.
+                    assert!(candidate[..num_input_bits].iter().all(|bit| bit.eject_value()));
+                    assert!(candidate[num_input_bits..].iter().all(|bit| bit.is_constant() && !bit.eject_value()));
+                }
+            }
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_hash_uncompressed_is_homomorphic_across_windows() -> Result<()> {
+        // Initialize the native BHP hasher.
+        let native = console::bhp::hasher::BHPHasher::<<Circuit as Environment>::Network, 32, 48>::setup(MESSAGE)?;
+        // Initialize the circuit BHP hasher with the native bases.
+        let circuit = BHPHasher::<Circuit, 32, 48>::from_bases(native.bases(), native.random_base());
+
+        // Determine the number of bits in one window.
+        let num_window_bits = 48 * BHP_CHUNK_SIZE;
+
+        let mut rng = TestRng::default();
+
+        for num_windows in [2, 5, 16] {
+            // Initialize a circuit BHP hasher whose bases start at window `num_windows`.
+            let mut rotated_bases = native.bases().to_vec();
+            rotated_bases.rotate_left(num_windows);
+            let offset = BHPHasher::<Circuit, 32, 48>::from_bases(&rotated_bases, native.random_base());
+
+            for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                // Sample a window-aligned input `a`, and an input `b` that is not window-aligned.
+                let a = (0..num_windows * num_window_bits).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+                let b = (0..1000).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+                let circuit_a: Vec<Boolean<_>> = Inject::new(mode, a);
+                let circuit_b: Vec<Boolean<_>> = Inject::new(mode, b);
+                let circuit_ab = [circuit_a.clone(), circuit_b.clone()].concat();
+
+                // Ensure `hash(a || b) == hash(a) + hash_at_window(b, num_windows)`.
+                let expected = circuit.hash_uncompressed(&circuit_ab);
+                let candidate = circuit.hash_uncompressed(&circuit_a) + offset.hash_uncompressed(&circuit_b);
+                assert_eq!(expected.eject_value(), candidate.eject_value());
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+
+                // Ensure the relationship does not hold if `a` is not window-aligned.
+                let unaligned_a = &circuit_ab[..circuit_a.len() + BHP_CHUNK_SIZE];
+                let unaligned_b = &circuit_ab[circuit_a.len() + BHP_CHUNK_SIZE..];
+                let candidate = circuit.hash_uncompressed(unaligned_a) + offset.hash_uncompressed(unaligned_b);
+                assert_ne!(expected.eject_value(), candidate.eject_value());
+                Circuit::reset();
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_uncompressed_cost_model() -> Result<()> {
+        // Initialize the circuit BHP hasher.
+        let primitive = console::BHP::<<Circuit as Environment>::Network, 32, 48>::setup(MESSAGE)?;
+        let circuit = BHPHasher::<Circuit, 32, 48>::new(Mode::Constant, primitive);
+
+        let mut rng = TestRng::default();
+
+        // Check window-aligned, chunk-aligned, and padded input sizes.
+        for num_input_bits in [145, 146, 147, 288, 289, 1000, 1024, 2304, 4607, 4608] {
+            // Compute the expected number of constraints.
+            let num_chunks = (num_input_bits + BHP_CHUNK_SIZE - 1) / BHP_CHUNK_SIZE;
+            let num_windows = (num_chunks + 48 - 1) / 48;
+            let num_padding_bits = (BHP_CHUNK_SIZE - num_input_bits % BHP_CHUNK_SIZE) % BHP_CHUNK_SIZE;
+            let expected = 5 * num_chunks + 5 * num_windows - 6 - usize::from(num_padding_bits == 2);
+
+            for mode in [Mode::Public, Mode::Private] {
+                // Sample a random input.
+                let input = (0..num_input_bits).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+                let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+                Circuit::scope(format!("BHP {mode} {num_input_bits}"), || {
+                    let _candidate = circuit.hash_uncompressed(&circuit_input);
+                    assert_eq!(expected as u64, Circuit::num_constraints_in_scope(), "{num_input_bits} bits");
+                });
+                Circuit::reset();
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_uncompressed_single_chunk_windows() -> Result<()> {
+        use console::HashUncompressed as H;
+
+        // Initialize the native BHP hasher, where each window is a single chunk.
+        let native = console::bhp::hasher::BHPHasher::<<Circuit as Environment>::Network, 64, 1>::setup(MESSAGE)?;
+        // Initialize the circuit BHP hasher with the exact bases of the native BHP hasher.
+        let circuit = BHPHasher::<Circuit, 64, 1>::from_native(&native);
+
+        let mut rng = TestRng::default();
+
+        // Check chunk-aligned and padded input sizes, up to the maximum input size.
+        for num_input_bits in [4, 5, 6, 100, 191, 192] {
+            // Compute the expected number of constraints, where each window costs 2 constraints,
+            // and each window after the first costs 6 for the group addition.
+            let num_windows = (num_input_bits + BHP_CHUNK_SIZE - 1) / BHP_CHUNK_SIZE;
+            let num_padding_bits = (BHP_CHUNK_SIZE - num_input_bits % BHP_CHUNK_SIZE) % BHP_CHUNK_SIZE;
+            let expected_num_constraints = 8 * num_windows - 6 - usize::from(num_padding_bits == 2);
+            // Ensure this is fewer constraints than the general path, which costs `5 * num_chunks + 5 * num_windows - 6`.
+            let general_num_constraints = 10 * num_windows - 6 - usize::from(num_padding_bits == 2);
+            assert!(expected_num_constraints < general_num_constraints);
+
+            for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                // Sample a random input.
+                let input = (0..num_input_bits).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+                let expected = native.hash_uncompressed(&input)?;
+                let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+                Circuit::scope(format!("BHP {mode} {num_input_bits}"), || {
+                    let candidate = circuit.hash_uncompressed(&circuit_input);
+                    assert_eq!(expected, candidate.eject_value(), "{num_input_bits} bits");
+                    match mode.is_constant() {
+                        true => assert_eq!(0, Circuit::num_constraints_in_scope(), "{num_input_bits} bits"),
+                        false => assert_eq!(
+                            expected_num_constraints as u64,
+                            Circuit::num_constraints_in_scope(),
+                            "{num_input_bits} bits"
+                        ),
+                    }
+                });
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_hash_uncompressed_rejects_invalid_sizes() -> Result<()> {
+        // Initialize the circuit BHP hasher.
+        let primitive = console::BHP::<<Circuit as Environment>::Network, 32, 48>::setup(MESSAGE)?;
+        let circuit = BHPHasher::<Circuit, 32, 48>::new(Mode::Constant, primitive);
+
+        type Hasher = BHPHasher<Circuit, 32, 48>;
+
+        for (num_bits, expected) in [
+            (
+                0,
+                BHPError::InputTooShort {
+                    num_bits: 0,
+                    min_bits: Hasher::MIN_BITS,
+                    num_missing_bits: Hasher::MIN_BITS + 1,
+                },
+            ),
+            (
+                Hasher::MIN_BITS,
+                BHPError::InputTooShort { num_bits: Hasher::MIN_BITS, min_bits: Hasher::MIN_BITS, num_missing_bits: 1 },
+            ),
+            (
+                Hasher::MAX_BITS + 1,
+                BHPError::InputTooLong { num_bits: Hasher::MAX_BITS + 1, max_bits: Hasher::MAX_BITS },
+            ),
+        ] {
+            let input: Vec<Boolean<_>> = Inject::new(Mode::Private, vec![false; num_bits]);
+
+            // Ensure the verifier-safe variant returns the error.
+            assert_eq!(Err(expected.clone()), circuit.try_hash_uncompressed(&input).map(|_| ()));
+            // Ensure the prover variant halts with the same message.
+            let result = std::panic::catch_unwind(|| circuit.hash_uncompressed(&input));
+            let message = result.err().and_then(|error| error.downcast_ref::<String>().cloned());
+            assert_eq!(Some(expected.to_string()), message);
+            Circuit::reset();
+        }
+
+        // Ensure the verifier-safe variant succeeds for the boundary sizes.
+        for num_bits in [Hasher::MIN_BITS + 1, Hasher::MAX_BITS] {
+            let input: Vec<Boolean<_>> = Inject::new(Mode::Private, vec![true; num_bits]);
+            let expected = circuit.hash_uncompressed(&input).eject_value();
+            assert_eq!(expected, circuit.try_hash_uncompressed(&input).unwrap().eject_value());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_hash_uncompressed_constant() -> Result<()> {
         check_hash_uncompressed::<32, 48>(Mode::Constant, 6239, 0, 0, 0)
@@ -246,4 +897,91 @@ mod tests {
     fn test_hash_uncompressed_private() -> Result<()> {
         check_hash_uncompressed::<32, 48>(Mode::Private, 65, 0, 7834, 7834)
     }
+
+    #[test]
+    fn test_montgomery_add_halts_on_equal_x_coordinates() -> Result<()> {
+        type Hasher = BHPHasher<Circuit, 8, 32>;
+
+        let native = console::BHP::<<Circuit as Environment>::Network, 8, 32>::setup(MESSAGE)?;
+        let circuit = Hasher::new(Mode::Constant, native);
+        let context = BHPContext::<Circuit>::new();
+
+        // Retrieve the first three lookups `[1, 2, 3] * base` of a base, in Montgomery form.
+        let (x_bases, y_bases) = &circuit.bases()[0][0];
+        let point = |i: usize, mode: Mode| {
+            (Field::<Circuit>::new(mode, x_bases[i].eject_value()), Field::new(mode, y_bases[i].eject_value()))
+        };
+
+        for mode in [Mode::Constant, Mode::Private] {
+            // Ensure the sum of distinct points is correct, i.e. `base + 2 * base == 3 * base`.
+            let ((this_x, this_y), (that_x, that_y)) = (point(0, mode), point(1, mode));
+            let (sum_x, sum_y) = Hasher::montgomery_add(&context, (&this_x, &this_y), (&that_x, &that_y));
+            assert_eq!(x_bases[2].eject_value(), sum_x.eject_value());
+            assert_eq!(y_bases[2].eject_value(), sum_y.eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+
+            // Ensure adding a point to itself, or to its negation, halts with a descriptive error.
+            let (this_x, this_y) = point(0, mode);
+            for that_y in [this_y.clone(), -&this_y] {
+                let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                    Hasher::montgomery_add(&context, (&this_x, &this_y), (&this_x, &that_y))
+                }));
+                let message = result.err().and_then(|error| error.downcast_ref::<String>().cloned());
+                assert!(message.unwrap().contains("undefined for points with equal x-coordinates"));
+                Circuit::reset();
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_interpolate_alternative_chunk_size() -> Result<()> {
+        type Hasher = BHPHasher<Circuit, 8, 32>;
+
+        // Use 4-bit chunks, which have 3 selector bits, and 8 lookups `[1, 2, ..., 8] * base`.
+        const CHUNK_SIZE: usize = 4;
+        let num_lookups = 1 << (CHUNK_SIZE - 1);
+
+        let mut rng = TestRng::default();
+        let base = console::Group::<<Circuit as Environment>::Network>::rand(&mut rng);
+
+        // Returns the given twisted Edwards point in Montgomery form.
+        let to_montgomery = |point: console::Group<<Circuit as Environment>::Network>| {
+            let one = console::Field::one();
+            let x = (one + point.to_y_coordinate()) / (one - point.to_y_coordinate());
+            (x, x / point.to_x_coordinate())
+        };
+        // Compute the lookups `[1, 2, ..., 8] * base` by repeated addition.
+        let multiples: Vec<_> =
+            (0..num_lookups).scan(base, |multiple, _| Some(core::mem::replace(multiple, *multiple + base))).collect();
+        let (x_bases, y_bases): (Vec<_>, Vec<_>) = multiples.iter().map(|multiple| to_montgomery(*multiple)).unzip();
+        let (x_bases, y_bases) = (Vec::<Field<Circuit>>::constant(x_bases), Vec::<Field<Circuit>>::constant(y_bases));
+        let one_half = BHPContext::<Circuit>::new().one_half;
+
+        for value in 0..(1u64 << CHUNK_SIZE) {
+            let bits: Vec<_> =
+                (0..CHUNK_SIZE).map(|i| Boolean::<Circuit>::new(Mode::Private, value >> i & 1 == 1)).collect();
+
+            Circuit::scope(format!("value {value}"), || {
+                let (selector_bits, sign_bit) = bits.split_at(CHUNK_SIZE - 1);
+                let selectors = Hasher::selector_products(selector_bits);
+                let x = Hasher::interpolate(&x_bases, &selectors);
+                let y =
+                    Hasher::conditionally_negate(&sign_bit[0], &Hasher::interpolate(&y_bases, &selectors), &one_half);
+
+                // Compute the expected point `(1 + selector value) * base`, negated if the sign bit is set.
+                let multiple = multiples[(value as usize) & (num_lookups - 1)];
+                let expected = if sign_bit[0].eject_value() { -multiple } else { multiple };
+                assert_eq!(to_montgomery(expected), (x.eject_value(), y.eject_value()));
+
+                // Ensure the 4 products of 2 or more selector bits, and the negation, each cost 1 variable and
+                // 1 constraint.
+                assert_scope!(0, 0, 5, 5);
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
 }
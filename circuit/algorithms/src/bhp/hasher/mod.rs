@@ -12,12 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod hash_cyclic_bases;
 mod hash_uncompressed;
 
 #[cfg(all(test, console))]
 use snarkvm_circuit_types::environment::assert_scope;
 
-use crate::HashUncompressed;
+use crate::{BHPContext, BHPError, HashUncompressed};
 use snarkvm_circuit_types::prelude::*;
 
 /// The BHP chunk size (this implementation is for a 3-bit BHP).
@@ -36,14 +37,15 @@ pub struct BHPHasher<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u
 }
 
 impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPHasher<E, NUM_WINDOWS, WINDOW_SIZE> {
-    /// The BHP lookup size per iteration.
-    const BHP_LOOKUP_SIZE: usize = 4;
+    /// The BHP lookup size per iteration, which tracks the chunk size.
+    /// The last bit of each chunk selects the sign of the lookup, so the table holds `2^(BHP_CHUNK_SIZE - 1)` powers,
+    /// which are interpolated over the remaining selector bits of the chunk.
+    const BHP_LOOKUP_SIZE: usize = 1 << (BHP_CHUNK_SIZE - 1);
     /// The maximum number of input bits.
     const MAX_BITS: usize = NUM_WINDOWS as usize * WINDOW_SIZE as usize * BHP_CHUNK_SIZE;
     /// The minimum number of input bits (at least one window).
     const MIN_BITS: usize = WINDOW_SIZE as usize * BHP_CHUNK_SIZE;
 
-    #[cfg(test)]
     /// Returns the bases.
     pub(crate) fn bases(&self) -> &Vec<Vec<BaseLookups<E>>> {
         &self.bases
@@ -61,41 +63,125 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> Inject for BH
 
     /// Initializes a new instance of a BHP circuit with the given BHP variant.
     fn new(_mode: Mode, bhp: Self::Primitive) -> Self {
+        Self::from_bases(bhp.bases(), bhp.random_base())
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPHasher<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Initializes a new instance of a BHP circuit hasher with the given native BHP hasher.
+    ///
+    /// This adapter injects the bases of the given native hasher as constants, which guarantees
+    /// the circuit and native hashers share the exact same generators in parity tests.
+    #[cfg(test)]
+    pub(crate) fn from_native(native: &console::bhp::hasher::BHPHasher<E::Network, NUM_WINDOWS, WINDOW_SIZE>) -> Self {
+        Self::from_bases(native.bases(), native.random_base())
+    }
+
+    /// Initializes a new instance of a BHP circuit hasher with the given native bases and random base.
+    ///
+    /// This method halts if the bases are invalid. To surface the error instead, use `try_from_bases`.
+    pub(super) fn from_bases(
+        bases: &[Vec<console::Group<E::Network>>],
+        random_base: &[console::Group<E::Network>],
+    ) -> Self {
+        match Self::try_from_bases(bases, random_base) {
+            Ok(hasher) => hasher,
+            Err(error) => E::halt(error.to_string()),
+        }
+    }
+
+    /// Initializes a new instance of a BHP circuit hasher with the given native bases and random base,
+    /// or returns a `BHPError` if the bases do not have the expected shape, or a base is degenerate.
+    pub(super) fn try_from_bases(
+        bases: &[Vec<console::Group<E::Network>>],
+        random_base: &[console::Group<E::Network>],
+    ) -> Result<Self, BHPError> {
+        // Ensure the bases have the expected shape.
+        if bases.len() != NUM_WINDOWS as usize {
+            return Err(BHPError::InvalidNumWindows { num_windows: bases.len(), expected: NUM_WINDOWS as usize });
+        }
+        if let Some((window, window_bases)) =
+            bases.iter().enumerate().find(|(_, window_bases)| window_bases.len() != WINDOW_SIZE as usize)
+        {
+            let (window_size, expected) = (window_bases.len(), WINDOW_SIZE as usize);
+            return Err(BHPError::InvalidWindowSize { window, window_size, expected });
+        }
+        let expected = console::Scalar::<E::Network>::size_in_bits();
+        if random_base.len() != expected {
+            return Err(BHPError::InvalidRandomBase { num_bases: random_base.len(), expected });
+        }
+
         // Compute the bases.
-        let bases = bhp
-            .bases()
+        let bases = Self::base_lookups(bases)?;
+        // Initialize the random base.
+        let random_base = Vec::constant(random_base.to_vec());
+
+        Ok(Self { bases, random_base })
+    }
+
+    /// Returns the Montgomery base lookups of each of the given native bases, computed natively,
+    /// or a `BHPError` if a base is degenerate.
+    pub(super) fn base_lookups(
+        bases: &[Vec<console::Group<E::Network>>],
+    ) -> Result<Vec<Vec<BaseLookups<E>>>, BHPError> {
+        bases
             .iter()
-            .take(NUM_WINDOWS as usize)
-            .map(|window| {
+            .enumerate()
+            .map(|(window_index, window)| {
                 // Construct the window with the base.
-                let mut powers = Vec::with_capacity(WINDOW_SIZE as usize);
-                for base in window.iter().take(WINDOW_SIZE as usize).map(|base| Group::constant(*base)) {
-                    let mut x_bases = Vec::with_capacity(Self::BHP_LOOKUP_SIZE);
-                    let mut y_bases = Vec::with_capacity(Self::BHP_LOOKUP_SIZE);
-                    let mut accumulator = base.clone();
-                    for _ in 0..Self::BHP_LOOKUP_SIZE {
-                        // Convert each base from twisted Edwards point into a Montgomery point.
-                        let x = (Field::one() + accumulator.to_y_coordinate())
-                            / (Field::one() - accumulator.to_y_coordinate());
-                        let y = &x / accumulator.to_x_coordinate();
-
-                        x_bases.push(x);
-                        y_bases.push(y);
-                        accumulator += &base;
-                    }
-                    powers.push((x_bases, y_bases));
-                }
-                powers
+                window
+                    .iter()
+                    .take(WINDOW_SIZE as usize)
+                    .enumerate()
+                    .map(|(base_index, base)| {
+                        // Compute the lookup powers of the base, ensuring they are distinct, non-identity points.
+                        let powers = Self::check_base_powers(window_index, base_index, base)?;
+                        Ok(powers
+                            .into_iter()
+                            .map(|power| {
+                                // Convert each base from twisted Edwards point into a Montgomery point.
+                                // Note: This conversion is computed natively, so that the intermediate values
+                                // (e.g. each coordinate accessed multiple times) do not allocate duplicate constants.
+                                let one = console::Field::<E::Network>::one();
+                                let x = (one + power.to_y_coordinate()) / (one - power.to_y_coordinate());
+                                let y = x / power.to_x_coordinate();
+                                (Field::constant(x), Field::constant(y))
+                            })
+                            .unzip())
+                    })
+                    .collect()
             })
-            .collect::<Vec<Vec<BaseLookups<E>>>>();
-        assert_eq!(bases.len(), NUM_WINDOWS as usize, "Incorrect number of BHP windows ({})", bases.len());
-        bases.iter().for_each(|window| assert_eq!(window.len(), WINDOW_SIZE as usize));
+            .collect()
+    }
 
-        // Initialize the random base.
-        let random_base = Vec::constant(bhp.random_base().iter().copied().collect());
-        assert_eq!(random_base.len(), console::Scalar::<E::Network>::size_in_bits());
+    /// Returns the lookup powers `[1, 2, ..., BHP_LOOKUP_SIZE] * base` of the given native base, at the given
+    /// base index in the given window, or a `BHPError` if the base is the identity, or if its powers are not
+    /// distinct, non-zero points.
+    ///
+    /// Within a chunk, the coordinate interpolation over the lookups assumes they are distinct points
+    /// on the Montgomery curve (which excludes the identity). A degenerate base of small order would violate this.
+    pub(super) fn check_base_powers(
+        window_index: usize,
+        base_index: usize,
+        base: &console::Group<E::Network>,
+    ) -> Result<Vec<console::Group<E::Network>>, BHPError> {
+        // Ensure the base is not the identity, which has no Montgomery representation.
+        if base.is_zero() {
+            return Err(BHPError::IdentityBase { window: window_index, base: base_index });
+        }
 
-        Self { bases, random_base }
+        let mut powers = Vec::with_capacity(Self::BHP_LOOKUP_SIZE);
+        let mut accumulator = *base;
+        for _ in 0..Self::BHP_LOOKUP_SIZE {
+            if accumulator.is_zero() || powers.contains(&accumulator) {
+                let num_powers = Self::BHP_LOOKUP_SIZE;
+                return Err(BHPError::DegenerateBase { window: window_index, base: base_index, num_powers });
+            }
+            powers.push(accumulator);
+            accumulator += base;
+        }
+        Ok(powers)
     }
 }
 
@@ -132,4 +218,208 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_check_base_powers() -> Result<()> {
+        type Hasher = BHPHasher<Circuit, 6, 43>;
+
+        // Ensure the real generators pass.
+        let native = console::BHP::<<Circuit as Environment>::Network, 6, 43>::setup(MESSAGE)?;
+        for (window_index, window) in native.bases().iter().enumerate() {
+            for (base_index, base) in window.iter().enumerate() {
+                assert_eq!(4, Hasher::check_base_powers(window_index, base_index, base).unwrap().len());
+            }
+        }
+
+        // Ensure a synthetic degenerate base set is rejected, including the identity and the point of order 2.
+        let zero = console::Field::<<Circuit as Environment>::Network>::zero();
+        let one = console::Field::<<Circuit as Environment>::Network>::one();
+        let identity = console::Group::<<Circuit as Environment>::Network>::zero();
+        let order_two = console::Group::<<Circuit as Environment>::Network>::from_xy_coordinates_unchecked(zero, -one);
+        assert!(!order_two.is_zero() && (order_two + order_two).is_zero());
+
+        for (degenerate, expected) in [
+            (identity, BHPError::IdentityBase { window: 0, base: 0 }),
+            (order_two, BHPError::DegenerateBase { window: 0, base: 0, num_powers: 4 }),
+        ] {
+            assert_eq!(Some(expected.clone()), Hasher::check_base_powers(0, 0, &degenerate).err());
+
+            let mut bases = native.bases().to_vec();
+            bases[0][0] = degenerate;
+            assert_eq!(Some(expected), Hasher::try_from_bases(&bases, native.random_base()).err());
+            let result = std::panic::catch_unwind(|| Hasher::from_bases(&bases, native.random_base()));
+            assert!(result.is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_from_bases_rejects_invalid_shape() -> Result<()> {
+        type Hasher = BHPHasher<Circuit, 6, 43>;
+
+        let native = console::BHP::<<Circuit as Environment>::Network, 6, 43>::setup(MESSAGE)?;
+
+        // Ensure an extra window is an error, instead of being dropped.
+        let mut bases = native.bases().to_vec();
+        bases.push(bases[0].clone());
+        let expected = BHPError::InvalidNumWindows { num_windows: 7, expected: 6 };
+        assert_eq!(Some(expected), Hasher::try_from_bases(&bases, native.random_base()).err());
+
+        // Ensure a window with too few bases is an error.
+        let mut bases = native.bases().to_vec();
+        bases[3].pop();
+        let expected = BHPError::InvalidWindowSize { window: 3, window_size: 42, expected: 43 };
+        assert_eq!(Some(expected), Hasher::try_from_bases(&bases, native.random_base()).err());
+
+        // Ensure a window with too many bases is an error, instead of being truncated.
+        let mut bases = native.bases().to_vec();
+        bases[3].push(native.bases()[3][0]);
+        let expected = BHPError::InvalidWindowSize { window: 3, window_size: 44, expected: 43 };
+        assert_eq!(Some(expected), Hasher::try_from_bases(&bases, native.random_base()).err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_setup_rejects_identity_base() -> Result<()> {
+        type Hasher = BHPHasher<Circuit, 6, 43>;
+
+        // Ensure the real generators pass.
+        let native = console::BHP::<<Circuit as Environment>::Network, 6, 43>::setup(MESSAGE)?;
+        let _hasher = Hasher::from_bases(native.bases(), native.random_base());
+        Circuit::reset();
+
+        // Ensure a synthetic base set containing the identity is rejected at setup, naming the identity base.
+        let mut bases = native.bases().to_vec();
+        bases[2][5] = console::Group::zero();
+        let result = std::panic::catch_unwind(|| Hasher::from_bases(&bases, native.random_base()));
+        let error = result.err().and_then(|error| error.downcast_ref::<String>().cloned()).unwrap_or_default();
+        assert_eq!("The BHP base 5 in window 2 is the identity", error);
+        Circuit::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_setup_base_powers_across_full_window() -> Result<()> {
+        let native = console::BHP::<<Circuit as Environment>::Network, 6, 43>::setup(MESSAGE)?;
+        let circuit = BHPHasher::<Circuit, 6, 43>::new(Mode::Constant, native.clone());
+
+        let one = console::Field::<<Circuit as Environment>::Network>::one();
+
+        for (native_window, circuit_window) in native.bases().iter().zip_eq(circuit.bases()) {
+            // Ensure the window is full.
+            assert_eq!(43, circuit_window.len());
+
+            // Reconstruct the base of each chunk from the window generator, as `2^{4*(j-1)}` times the generator.
+            let mut expected_base = native_window[0];
+            for (j, (x_bases, y_bases)) in circuit_window.iter().enumerate() {
+                assert_eq!(expected_base, native_window[j], "Incorrect native base for chunk {j}");
+
+                // Ensure each lookup is the corresponding multiple of the chunk base, in Montgomery form.
+                let mut multiple = expected_base;
+                for (k, (x_base, y_base)) in x_bases.iter().zip_eq(y_bases).enumerate() {
+                    let x = (one + multiple.to_y_coordinate()) / (one - multiple.to_y_coordinate());
+                    let y = x / multiple.to_x_coordinate();
+                    assert_eq!(x, x_base.eject_value(), "Incorrect x-base for lookup {k} of chunk {j}");
+                    assert_eq!(y, y_base.eject_value(), "Incorrect y-base for lookup {k} of chunk {j}");
+                    multiple += expected_base;
+                }
+
+                // Advance to the base of the next chunk.
+                for _ in 0..4 {
+                    expected_base = expected_base.double();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_lookup_size_tracks_chunk_size() -> Result<()> {
+        // Ensure the default chunk size of 3 bits yields 4 lookups per base.
+        assert_eq!(3, BHP_CHUNK_SIZE);
+        assert_eq!(4, BHPHasher::<Circuit, 6, 43>::BHP_LOOKUP_SIZE);
+
+        // Ensure each base in the setup holds exactly one lookup per power.
+        let native = console::BHP::<<Circuit as Environment>::Network, 6, 43>::setup(MESSAGE)?;
+        let circuit = BHPHasher::<Circuit, 6, 43>::new(Mode::Constant, native);
+        for (x_bases, y_bases) in circuit.bases().iter().flatten() {
+            assert_eq!(BHPHasher::<Circuit, 6, 43>::BHP_LOOKUP_SIZE, x_bases.len());
+            assert_eq!(BHPHasher::<Circuit, 6, 43>::BHP_LOOKUP_SIZE, y_bases.len());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_generators_agree_across_seeds() -> Result<()> {
+        use console::HashUncompressed as H;
+        use snarkvm_utilities::{TestRng, Uniform};
+
+        let mut rng = TestRng::default();
+
+        for seed in ["BHPCircuit0", "BHPCircuit1", "AleoBHP", ""] {
+            // Derive the native hasher and the circuit hasher from the same seed.
+            let native = console::bhp::hasher::BHPHasher::<<Circuit as Environment>::Network, 8, 32>::setup(seed)?;
+            let generators = console::bhp::hasher::bhp_generators::<<Circuit as Environment>::Network, 8, 32>(seed)?;
+            let circuit = BHPHasher::<Circuit, 8, 32>::from_bases(&generators, native.random_base());
+            assert_eq!(native.bases().as_slice(), generators.as_slice());
+
+            for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                // Sample a random input.
+                let input =
+                    (0..BHPHasher::<Circuit, 8, 32>::MAX_BITS).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+                // Ensure the native and circuit hashers agree.
+                let expected = native.hash_uncompressed(&input)?;
+                let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+                assert_eq!(expected, circuit.hash_uncompressed(&circuit_input).eject_value(), "Seed '{seed}'");
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_setup_deduplicates_constants() -> Result<()> {
+        type Hasher = BHPHasher<Circuit, 8, 32>;
+
+        let native = console::BHP::<<Circuit as Environment>::Network, 8, 32>::setup(MESSAGE)?;
+
+        // Initialize the circuit hasher, counting the number of constants allocated.
+        let circuit = Circuit::scope("setup", || Hasher::new(Mode::Constant, native.clone()));
+        let num_constants = Circuit::num_constants();
+        Circuit::reset();
+
+        // Compute the bases lookups by converting each base in the circuit, as a reference.
+        Circuit::scope("reference", || {
+            native.bases().iter().zip(circuit.bases.iter()).for_each(|(native_bases, circuit_bases)| {
+                native_bases.iter().zip(circuit_bases).for_each(|(native_base, (x_bases, y_bases))| {
+                    let base = Group::<Circuit>::constant(*native_base);
+                    let mut accumulator = base.clone();
+                    for (x_base, y_base) in x_bases.iter().zip(y_bases) {
+                        let x = (Field::one() + accumulator.to_y_coordinate())
+                            / (Field::one() - accumulator.to_y_coordinate());
+                        let y = &x / accumulator.to_x_coordinate();
+                        // Ensure the output is unchanged.
+                        assert_eq!(x.eject_value(), x_base.eject_value());
+                        assert_eq!(y.eject_value(), y_base.eject_value());
+                        accumulator += &base;
+                    }
+                })
+            });
+        });
+        let num_reference_constants = Circuit::num_constants();
+        Circuit::reset();
+
+        // Count the constants of the random base, which the setup allocates in addition to the bases lookups.
+        Circuit::scope("random base", || Vec::<Group<Circuit>>::constant(native.random_base().to_vec()));
+        let num_random_base_constants = Circuit::num_constants();
+        Circuit::reset();
+
+        // Ensure the setup allocates exactly the two coordinates of each lookup, and the random base.
+        let num_lookup_constants = num_constants - num_random_base_constants;
+        assert_eq!((2 * 8 * 32 * Hasher::BHP_LOOKUP_SIZE) as u64, num_lookup_constants);
+        // Ensure the lookups allocate at most half the constants of the reference conversion.
+        assert!(2 * num_lookup_constants <= num_reference_constants);
+        Ok(())
+    }
 }
@@ -0,0 +1,147 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A Merkle tree hasher composed of two BHP instances, which hashes the leaves with the `Leaf` BHP,
+/// and merges the internal nodes with the `Node` BHP.
+///
+/// This supports wide-then-narrow hashing, where the leaves are hashed with a large-parameter BHP
+/// (to absorb wide leaves in one iteration), and the nodes are merged with a small-parameter BHP.
+pub struct LayeredBHP<Leaf, Node> {
+    /// The BHP used to hash the leaves.
+    leaf: Leaf,
+    /// The BHP used to merge the internal nodes.
+    node: Node,
+}
+
+impl<
+    E: Environment,
+    const LEAF_NUM_WINDOWS: u8,
+    const LEAF_WINDOW_SIZE: u8,
+    const NODE_NUM_WINDOWS: u8,
+    const NODE_WINDOW_SIZE: u8,
+> LayeredBHP<BHP<E, LEAF_NUM_WINDOWS, LEAF_WINDOW_SIZE>, BHP<E, NODE_NUM_WINDOWS, NODE_WINDOW_SIZE>>
+{
+    /// Initializes a new layered BHP from the given leaf BHP and node BHP.
+    pub fn new(
+        leaf: BHP<E, LEAF_NUM_WINDOWS, LEAF_WINDOW_SIZE>,
+        node: BHP<E, NODE_NUM_WINDOWS, NODE_WINDOW_SIZE>,
+    ) -> Self {
+        Self { leaf, node }
+    }
+
+    /// Returns the BHP used to hash the leaves.
+    pub const fn leaf(&self) -> &BHP<E, LEAF_NUM_WINDOWS, LEAF_WINDOW_SIZE> {
+        &self.leaf
+    }
+
+    /// Returns the BHP used to merge the internal nodes.
+    pub const fn node(&self) -> &BHP<E, NODE_NUM_WINDOWS, NODE_WINDOW_SIZE> {
+        &self.node
+    }
+
+    /// Returns the leaf hash of the given leaf with the leaf BHP.
+    ///
+    /// The preimage is `[ 0 || LEAF ]`, matching the native BHP `LeafHash::hash_leaf`.
+    pub fn hash_leaf(&self, leaf: &[Boolean<E>]) -> Field<E> {
+        // Initialize a vector for the hash preimage.
+        let mut preimage = Vec::with_capacity(1 + leaf.len());
+        // Prepend the leaf with a `false` bit.
+        preimage.push(Boolean::constant(false));
+        preimage.extend_from_slice(leaf);
+        // Hash the preimage.
+        self.leaf.hash_field(&preimage)
+    }
+
+    /// Returns the hash of the given left and right digests with the node BHP, as an internal Merkle tree node.
+    pub fn merge(&self, left: &Field<E>, right: &Field<E>) -> Field<E> {
+        self.node.merge(left, right)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "BHPCircuit0";
+
+    type NativeField = console::Field<<Circuit as Environment>::Network>;
+
+    #[test]
+    fn test_layered_tree() -> Result<()> {
+        use console::{Hash as H, ToBits as TB};
+
+        // Initialize the leaf and node BHPs with distinct parameterizations.
+        let native_leaf = console::BHP1024::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let native_node = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let layered = LayeredBHP::new(
+            BHP1024::<Circuit>::constant(native_leaf.clone()),
+            BHP512::<Circuit>::constant(native_node.clone()),
+        );
+
+        // Returns the native leaf hash of the given leaf, with the leaf BHP.
+        let native_hash_leaf = |leaf: &[bool]| {
+            let mut preimage = vec![false];
+            preimage.extend_from_slice(leaf);
+            native_leaf.hash(&preimage)
+        };
+        // Returns the native hash of the given left and right digests, with the node BHP.
+        let native_merge = |left: &NativeField, right: &NativeField| {
+            let mut preimage = vec![true];
+            left.write_bits_le(&mut preimage);
+            right.write_bits_le(&mut preimage);
+            native_node.hash(&preimage)
+        };
+
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Sample four wide leaves, which fit in one iteration of the leaf BHP, but not of the node BHP.
+            let leaves =
+                (0..4).map(|_| (0..1000).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>()).collect::<Vec<_>>();
+
+            // Compute the expected root.
+            let hashes = leaves.iter().map(|leaf| native_hash_leaf(leaf)).collect::<Result<Vec<_>>>()?;
+            let left = native_merge(&hashes[0], &hashes[1])?;
+            let right = native_merge(&hashes[2], &hashes[3])?;
+            let expected = native_merge(&left, &right)?;
+
+            // Compute the candidate root.
+            let hashes = leaves
+                .iter()
+                .map(|leaf| {
+                    let leaf: Vec<Boolean<_>> = Inject::new(mode, leaf.clone());
+                    layered.hash_leaf(&leaf)
+                })
+                .collect::<Vec<_>>();
+            let left = layered.merge(&hashes[0], &hashes[1]);
+            let right = layered.merge(&hashes[2], &hashes[3]);
+            let candidate = layered.merge(&left, &right);
+            assert_eq!(expected, candidate.eject_value());
+
+            // Ensure the leaves are hashed with the leaf BHP, and not the node BHP.
+            let mut preimage = vec![false];
+            preimage.extend_from_slice(&leaves[0]);
+            assert_ne!(native_node.hash(&preimage)?, hashes[0].eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,138 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the BHP hash of the given left and right digests, as an internal Merkle tree node.
+    ///
+    /// The preimage is `[ 1 || LEFT || RIGHT ]`, matching the native BHP `PathHash::hash_children`.
+    pub fn merge(&self, left: &Field<E>, right: &Field<E>) -> Field<E> {
+        // Initialize a vector for the hash preimage.
+        let mut preimage = Vec::with_capacity(1 + 2 * console::Field::<E::Network>::size_in_bits());
+        // Prepend the nodes with a `true` bit.
+        preimage.push(Boolean::constant(true));
+        left.write_bits_le(&mut preimage);
+        right.write_bits_le(&mut preimage);
+        // Hash the preimage.
+        self.hash_field(&preimage)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+    const DOMAIN: &str = "BHPCircuit0";
+
+    type NativeField = console::Field<<Circuit as Environment>::Network>;
+
+    /// Returns the native BHP hash of the given left and right digests.
+    fn native_merge(
+        native: &console::BHP512<<Circuit as Environment>::Network>,
+        left: &NativeField,
+        right: &NativeField,
+    ) -> Result<NativeField> {
+        use console::{Hash as H, ToBits as TB};
+
+        let mut preimage = vec![true];
+        left.write_bits_le(&mut preimage);
+        right.write_bits_le(&mut preimage);
+        native.hash(&preimage)
+    }
+
+    fn check_merge(
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample the left and right digests.
+            let left = Uniform::rand(&mut rng);
+            let right = Uniform::rand(&mut rng);
+            // Compute the expected digest.
+            let expected = native_merge(&native, &left, &right)?;
+            // Prepare the circuit digests.
+            let circuit_left = Field::new(mode, left);
+            let circuit_right = Field::new(mode, right);
+
+            Circuit::scope(format!("BHP {mode} {i}"), || {
+                let candidate = circuit.merge(&circuit_left, &circuit_right);
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+                assert_eq!(expected, candidate.eject_value());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_constant() -> Result<()> {
+        check_merge(Mode::Constant, 508, 0, 0, 0)
+    }
+
+    #[test]
+    fn test_merge_public() -> Result<()> {
+        check_merge(Mode::Public, 409, 0, 1879, 1883)
+    }
+
+    #[test]
+    fn test_merge_private() -> Result<()> {
+        check_merge(Mode::Private, 409, 0, 1879, 1883)
+    }
+
+    #[test]
+    fn test_merge_four_leaf_tree() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Sample the leaves.
+            let leaves = (0..4).map(|_| Uniform::rand(&mut rng)).collect::<Vec<NativeField>>();
+
+            // Compute the expected root.
+            let left = native_merge(&native, &leaves[0], &leaves[1])?;
+            let right = native_merge(&native, &leaves[2], &leaves[3])?;
+            let expected = native_merge(&native, &left, &right)?;
+
+            // Compute the candidate root.
+            let circuit_leaves = leaves.iter().map(|leaf| Field::<Circuit>::new(mode, *leaf)).collect::<Vec<_>>();
+            Circuit::scope(format!("BHP {mode}"), || {
+                let left = circuit.merge(&circuit_leaves[0], &circuit_leaves[1]);
+                let right = circuit.merge(&circuit_leaves[2], &circuit_leaves[3]);
+                let candidate = circuit.merge(&left, &right);
+                assert_eq!(expected, candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A report of the cost breakdown of a BHP hash, as measured by the hasher.
+///
+/// Note: The operations on constant chunks (e.g. the domain) are folded into constants, and are not counted.
+/// An entirely constant input that is hashed by the native BHP evaluates no operations in the circuit.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct BHPMetrics {
+    /// The number of windows evaluated (including constant-folded windows), across all iterations.
+    pub windows_used: u64,
+    /// The number of 3-bit chunks with a non-constant bit, across all iterations.
+    pub chunks: u64,
+    /// The number of Montgomery additions with a non-constant operand, across all iterations.
+    pub montgomery_adds: u64,
+    /// The number of field divisions with a non-constant operand, which is one per Montgomery addition
+    /// (for `lambda`), and two per window (for the conversion into a twisted Edwards point).
+    pub field_inversions: u64,
+    /// The number of constraints synthesized by the hash.
+    pub constraints: u64,
+}
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the BHP hash of the given input as a field element, along with the cost breakdown of the hash.
+    pub fn hash_with_metrics(&self, input: &[Boolean<E>]) -> (Field<E>, BHPMetrics) {
+        // Hash the input in a context that records the windows it evaluates, and count the number of constraints.
+        let context = BHPContext::with_trace();
+        let num_constraints = E::num_constraints();
+        let digest = match self.try_hash_iterations(Some(&context), input) {
+            Ok(digest) => digest.to_x_coordinate(),
+            Err(error) => E::halt(error.to_string()),
+        };
+        let constraints = E::num_constraints() - num_constraints;
+
+        // Report the operations measured by the hasher.
+        let trace = context.trace().unwrap_or_default();
+        let metrics = BHPMetrics {
+            windows_used: trace.windows.iter().map(|windows| windows.len() as u64).sum(),
+            chunks: trace.chunks,
+            montgomery_adds: trace.montgomery_adds,
+            field_inversions: trace.field_inversions,
+            constraints,
+        };
+
+        (digest, metrics)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "BHPCircuit0";
+
+    fn check_hash_with_metrics(mode: Mode, num_input_bits: usize, expected: BHPMetrics) -> Result<()> {
+        use console::Hash as H;
+
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+
+        let mut rng = TestRng::default();
+
+        // Sample a random input.
+        let input = (0..num_input_bits).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+        let expected_digest = native.hash(&input)?;
+        let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+        Circuit::scope(format!("BHP {mode} {num_input_bits}"), || {
+            let (candidate, metrics) = circuit.hash_with_metrics(&circuit_input);
+            assert_eq!(expected_digest, candidate.eject_value());
+            assert_eq!(expected, metrics);
+            assert_eq!(Circuit::num_constraints_in_scope(), metrics.constraints);
+        });
+        Circuit::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_with_metrics() -> Result<()> {
+        // BHP512 has 6 windows of 43 chunks (129 bits), and the preimage of each iteration begins with 252 data bits,
+        // so each iteration hashes up to 522 input bits. The domain and length prefix of the first iteration
+        // is constant.
+        //
+        // For 200 private input bits, the single iteration hashes 452 bits in 4 windows, where window 0 is constant,
+        // window 1 has 2 non-constant chunks, window 2 has 43, and window 3 has 22. This costs 2 + 42 + 21 = 65
+        // Montgomery additions, and 65 + 2 * 3 = 71 field divisions.
+        let one_iteration =
+            BHPMetrics { windows_used: 4, chunks: 67, montgomery_adds: 65, field_inversions: 71, constraints: 350 };
+        // For 523 private input bits, the first iteration hashes 774 bits in 6 windows, where window 0 is constant,
+        // window 1 has 2 non-constant chunks, and windows 2 to 5 have 43 each. The second iteration hashes
+        // 253 non-constant bits, in 85 chunks over 2 windows. This costs (2 + 4 * 42) + (85 - 2) = 253 Montgomery additions,
+        // and 253 + 2 * 7 = 267 field divisions.
+        let two_iterations =
+            BHPMetrics { windows_used: 8, chunks: 259, montgomery_adds: 253, field_inversions: 267, constraints: 1830 };
+
+        // A constant input is hashed natively, so no operation is evaluated in the circuit.
+        check_hash_with_metrics(Mode::Constant, 200, BHPMetrics::default())?;
+        check_hash_with_metrics(Mode::Private, 200, one_iteration)?;
+        check_hash_with_metrics(Mode::Constant, 523, BHPMetrics::default())?;
+        check_hash_with_metrics(Mode::Private, 523, two_iterations)
+    }
+}
@@ -15,10 +15,74 @@
 mod hasher;
 use hasher::BHPHasher;
 
+mod bit_order;
+pub use bit_order::BitOrder;
+
+mod context;
+pub use context::BHPContext;
+
+mod digest;
+pub use digest::BHPDigest;
+
+mod error;
+pub use error::BHPError;
+
+mod layered;
+pub use layered::LayeredBHP;
+
+mod metrics;
+pub use metrics::BHPMetrics;
+
+mod opening;
+pub use opening::BHPOpening;
+
+mod transcript;
+pub use transcript::BHPTranscript;
+
+mod assert_canonical_preimage;
 mod commit;
 mod commit_uncompressed;
+mod compute_root;
+mod enforce_hash;
+#[cfg(console)]
+mod from_bases;
 mod hash;
+mod hash_as_mode;
+mod hash_bounded;
+mod hash_bytes_with_order;
+mod hash_concat;
+mod hash_cyclic_bases;
+mod hash_dry_run;
+mod hash_in_subgroup;
+mod hash_incremental;
+mod hash_into;
+mod hash_iter;
+mod hash_length_prefixed;
+mod hash_link;
+mod hash_mixed_widths;
+mod hash_nonzero;
+mod hash_records;
+mod hash_salted;
+#[cfg(console)]
+mod hash_small;
+mod hash_tagged;
+mod hash_to_bits;
+mod hash_to_challenge;
+#[cfg(feature = "debug-graph")]
+mod hash_to_dot;
+mod hash_truncated;
 mod hash_uncompressed;
+mod hash_versioned;
+mod hash_windows_input;
+mod hash_with_checksum;
+mod hash_with_metadata;
+mod hash_with_padding_flag;
+mod hash_with_scratch;
+mod hash_with_window_map;
+mod merge;
+mod parameters_digest;
+#[cfg(console)]
+mod serialize;
 
 #[cfg(all(test, console))]
 use snarkvm_circuit_types::environment::assert_scope;
@@ -52,11 +116,34 @@ const BHP_CHUNK_SIZE: usize = 3;
 /// ```text
 /// DIGEST_N+1 = BHP([ DIGEST_N[0..DATA_BITS] || INPUT[(N+1)*BLOCK_SIZE..(N+2)*BLOCK_SIZE] ]);
 /// ```
+///
+/// ## Thread Safety
+/// The BHP circuit is neither `Send` nor `Sync`, as its bases are constants in the thread-local circuit,
+/// and are backed by the reference-counted variables of that circuit. To hash across threads,
+/// share the native BHP (which is `Send + Sync`), and inject it as a constant in each thread.
+///
+/// ```compile_fail
+/// # use snarkvm_circuit_algorithms::BHP;
+/// # use snarkvm_circuit_types::environment::Circuit;
+/// fn assert_send<T: Send>() {}
+/// assert_send::<BHP<Circuit, 32, 48>>();
+/// ```
+///
+/// ```compile_fail
+/// # use snarkvm_circuit_algorithms::BHP;
+/// # use snarkvm_circuit_types::environment::Circuit;
+/// fn assert_sync<T: Sync>() {}
+/// assert_sync::<BHP<Circuit, 32, 48>>();
+/// ```
 pub struct BHP<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> {
     /// The domain separator for the BHP hash function.
     domain: Vec<Boolean<E>>,
     /// The internal BHP hasher used to process one iteration.
     hasher: BHPHasher<E, NUM_WINDOWS, WINDOW_SIZE>,
+    /// The native BHP, if known, which is used to hash entirely constant inputs.
+    /// Its generators are reference-counted, so they are shared with the given native BHP, not copied.
+    #[cfg(console)]
+    native: Option<console::BHP<E::Network, NUM_WINDOWS, WINDOW_SIZE>>,
 }
 
 #[cfg(console)]
@@ -78,9 +165,34 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> Inject for BH
         let domain = Vec::constant(bhp.domain().to_vec());
 
         // Initialize the BHP hasher.
-        let hasher = BHPHasher::<E, NUM_WINDOWS, WINDOW_SIZE>::constant(bhp);
+        let hasher = BHPHasher::<E, NUM_WINDOWS, WINDOW_SIZE>::from_bases(bhp.bases(), bhp.random_base());
 
-        Self { domain, hasher }
+        Self { domain, hasher, native: Some(bhp) }
+    }
+}
+
+#[cfg(all(test, console))]
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Initializes a new instance of a BHP circuit by injecting the domain and bases of the given native BHP as constants.
+    ///
+    /// This guarantees the circuit uses exactly the native bases in parity tests. Unlike `Inject`, the native BHP
+    /// is not retained, so constant inputs are also hashed by the circuit logic, and any output mismatch
+    /// is a bug in the circuit logic. The bases are constants, regardless of the given mode.
+    pub(crate) fn from_native_bases(native: &console::BHP<E::Network, NUM_WINDOWS, WINDOW_SIZE>, _mode: Mode) -> Self {
+        // Initialize the domain.
+        let domain = Vec::constant(native.domain().to_vec());
+        // Initialize the BHP hasher with the native bases.
+        let hasher = BHPHasher::<E, NUM_WINDOWS, WINDOW_SIZE>::from_bases(native.bases(), native.random_base());
+
+        Self { domain, hasher, native: None }
+    }
+
+    /// Returns the BHP hash of the given native bits as a field element, by injecting the bits with the given mode.
+    ///
+    /// This is a convenience for tests, which is equivalent to hashing the output of `Inject::new(mode, bits)`.
+    pub(crate) fn hash_native_bits(&self, bits: &[bool], mode: Mode) -> Field<E> {
+        let input: Vec<Boolean<E>> = Inject::new(mode, bits.to_vec());
+        self.hash_field(input)
     }
 }
 
@@ -117,4 +229,33 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_hash_across_threads() -> Result<()> {
+        // Ensure the native BHP is `Send + Sync`.
+        // Note: The circuit BHP is neither, which is checked by the `compile_fail` examples of `BHP`.
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<console::BHP<<Circuit as Environment>::Network, 32, 48>>();
+
+        let native = console::BHP::<<Circuit as Environment>::Network, 32, 48>::setup(MESSAGE)?;
+        let input = (0..4608).map(|i| i % 3 == 0).collect::<Vec<bool>>();
+
+        // Hashes the input with a BHP circuit injected from the shared native BHP, in the current thread.
+        let hash = |native: &console::BHP<<Circuit as Environment>::Network, 32, 48>| {
+            let circuit = BHP::<Circuit, 32, 48>::constant(native.clone());
+            let circuit_input: Vec<Boolean<_>> = Inject::new(Mode::Private, input.clone());
+            let digest = circuit.hash_field(circuit_input);
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+            digest.eject_value()
+        };
+
+        // Ensure each thread computes the same digest as the current thread.
+        let expected = hash(&native);
+        std::thread::scope(|scope| {
+            let handles = (0..4).map(|_| scope.spawn(|| hash(&native))).collect::<Vec<_>>();
+            handles.into_iter().for_each(|handle| assert_eq!(expected, handle.join().unwrap()));
+        });
+        Ok(())
+    }
 }
@@ -0,0 +1,163 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// An opening of a BHP commitment, which bundles the committed input and the randomizer,
+/// so that the commitment can be verified at a later point in the circuit.
+#[derive(Clone)]
+pub struct BHPOpening<E: Environment> {
+    /// The committed input bits.
+    input: Vec<Boolean<E>>,
+    /// The randomizer of the commitment.
+    randomizer: Scalar<E>,
+}
+
+impl<E: Environment> BHPOpening<E> {
+    /// Initializes a new BHP opening from the given input and randomizer.
+    pub fn new(input: Vec<Boolean<E>>, randomizer: Scalar<E>) -> Self {
+        Self { input, randomizer }
+    }
+
+    /// Returns the committed input bits.
+    pub fn input(&self) -> &[Boolean<E>] {
+        &self.input
+    }
+
+    /// Returns the randomizer of the commitment.
+    pub const fn randomizer(&self) -> &Scalar<E> {
+        &self.randomizer
+    }
+}
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the BHP commitment of the given input and randomizer as a field element,
+    /// along with the opening to verify the commitment later with `verify_opening`.
+    pub fn commit_with_opening(&self, input: &[Boolean<E>], randomizer: &Scalar<E>) -> (Field<E>, BHPOpening<E>) {
+        // Compute the commitment.
+        let commitment = self.commit(input, randomizer);
+        // Construct the opening.
+        let opening = BHPOpening::new(input.to_vec(), randomizer.clone());
+        (commitment, opening)
+    }
+
+    /// Returns `true` if the given opening opens the given commitment.
+    pub fn verify_opening(&self, commitment: &Field<E>, opening: &BHPOpening<E>) -> Boolean<E> {
+        self.commit(opening.input(), opening.randomizer()).is_equal(commitment)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+    const DOMAIN: &str = "BHPCircuit0";
+
+    fn check_verify_opening(
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) -> Result<()> {
+        use console::Commit as C;
+
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random input and randomizer.
+            let input = (0..522).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+            let randomizer = Uniform::rand(&mut rng);
+            // Compute the expected commitment.
+            let expected = native.commit(&input, &randomizer)?;
+            // Prepare the circuit input and randomizer.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+            let circuit_randomizer: Scalar<_> = Inject::new(mode, randomizer);
+
+            // Commit to the input, and retrieve the opening.
+            let (commitment, opening) = circuit.commit_with_opening(&circuit_input, &circuit_randomizer);
+            assert_eq!(expected, commitment.eject_value());
+            assert_eq!(circuit_input.eject_value(), opening.input().eject_value());
+            assert_eq!(randomizer, opening.randomizer().eject_value());
+
+            Circuit::scope(format!("BHP {mode} {i}"), || {
+                // Ensure the opening verifies.
+                let candidate = circuit.verify_opening(&commitment, &opening);
+                assert_scope!(<=num_constants, num_public, num_private, num_constraints);
+                assert!(candidate.eject_value());
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_opening_constant() -> Result<()> {
+        check_verify_opening(Mode::Constant, 2200, 0, 0, 0)
+    }
+
+    #[test]
+    fn test_verify_opening_public() -> Result<()> {
+        check_verify_opening(Mode::Public, 911, 0, 2403, 2403)
+    }
+
+    #[test]
+    fn test_verify_opening_private() -> Result<()> {
+        check_verify_opening(Mode::Private, 911, 0, 2403, 2403)
+    }
+
+    #[test]
+    fn test_verify_opening_rejects_tampered_openings() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit = BHP512::<Circuit>::constant(native);
+
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Sample a random input and randomizer.
+            let input: Vec<Boolean<_>> = Inject::new(mode, (0..522).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>());
+            let randomizer = Scalar::new(mode, Uniform::rand(&mut rng));
+
+            // Commit to the input, and retrieve the opening.
+            let (commitment, opening) = circuit.commit_with_opening(&input, &randomizer);
+
+            // Tamper with the input.
+            let mut tampered_input = opening.input().to_vec();
+            tampered_input[0] = !&tampered_input[0];
+            let tampered = BHPOpening::new(tampered_input, opening.randomizer().clone());
+            assert!(!circuit.verify_opening(&commitment, &tampered).eject_value());
+
+            // Tamper with the randomizer.
+            let tampered = BHPOpening::new(opening.input().to_vec(), opening.randomizer() + Scalar::one());
+            assert!(!circuit.verify_opening(&commitment, &tampered).eject_value());
+
+            // Ensure the untampered opening still verifies.
+            assert!(circuit.verify_opening(&commitment, &opening).eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns a fingerprint of the generator set, as the BHP hash (with this BHP) of the parameters.
+    ///
+    /// The parameters are serialized as the little-endian bits of the Montgomery coordinates of each base,
+    /// followed by the twisted Edwards coordinates of each random base. As the parameters are constants,
+    /// the digest is a constant, which lets a verifier assert the prover used the expected parameters.
+    ///
+    /// Note: This hashes every base, which allocates many constants, so the digest should be computed once.
+    pub fn parameters_digest(&self) -> Field<E> {
+        // Initialize a vector for the serialized parameters.
+        let mut preimage = Vec::new();
+        // Serialize the first lookup of each base, which is the base itself.
+        for (x_bases, y_bases) in self.hasher.bases().iter().flatten() {
+            x_bases[0].write_bits_le(&mut preimage);
+            y_bases[0].write_bits_le(&mut preimage);
+        }
+        // Serialize the random bases.
+        for random_base in self.hasher.random_base() {
+            random_base.to_x_coordinate().write_bits_le(&mut preimage);
+            random_base.to_y_coordinate().write_bits_le(&mut preimage);
+        }
+        // Hash the serialized parameters.
+        self.hash_field(&preimage)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    use anyhow::Result;
+
+    #[test]
+    fn test_parameters_digest() -> Result<()> {
+        // Initialize BHP with two distinct seeds, and again with the first seed.
+        let first = BHP256::<Circuit>::constant(console::BHP256::setup("BHPCircuit0")?);
+        let second = BHP256::<Circuit>::constant(console::BHP256::setup("BHPCircuit1")?);
+        let repeat = BHP256::<Circuit>::constant(console::BHP256::setup("BHPCircuit0")?);
+
+        Circuit::scope("parameters_digest", || {
+            let digest = first.parameters_digest();
+            assert!(digest.is_constant());
+            assert_scope!(213534, 0, 0, 0);
+
+            // Ensure different seeds have different parameter digests, and identical seeds match.
+            assert_ne!(digest.eject_value(), second.parameters_digest().eject_value());
+            assert_eq!(digest.eject_value(), repeat.parameters_digest().eject_value());
+        });
+        Ok(())
+    }
+}
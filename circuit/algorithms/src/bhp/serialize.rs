@@ -0,0 +1,187 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// The affine `(x, y)` coordinates of a native twisted Edwards point.
+type Coordinates<E> = (console::Field<<E as Environment>::Network>, console::Field<<E as Environment>::Network>);
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> Serialize for BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Serializes the BHP parameters as the domain bits, and the native affine coordinates
+    /// of the base of each chunk in each window and of the random base.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let one = console::Field::<E::Network>::one();
+
+        // Eject the domain.
+        let domain = self.domain.eject_value();
+        // Recover the twisted Edwards coordinates of each base, from its first Montgomery lookup.
+        let bases = self
+            .hasher
+            .bases()
+            .iter()
+            .map(|window| {
+                window
+                    .iter()
+                    .map(|(x_bases, y_bases)| {
+                        let (x, y) = (x_bases[0].eject_value(), y_bases[0].eject_value());
+                        (x / y, (x - one) / (x + one))
+                    })
+                    .collect::<Vec<Coordinates<E>>>()
+            })
+            .collect::<Vec<_>>();
+        // Eject the coordinates of the random base.
+        let random_base =
+            self.hasher.random_base().iter().map(|base| base.eject_value().to_xy_coordinates()).collect::<Vec<_>>();
+
+        (domain, bases, random_base).serialize(serializer)
+    }
+}
+
+impl<'de, E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> Deserialize<'de>
+    for BHP<E, NUM_WINDOWS, WINDOW_SIZE>
+{
+    /// Deserializes the BHP parameters, ensuring the number of windows and chunks match the const generics,
+    /// and that each base is a valid, non-identity point in the prime-order subgroup.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        type Parameters<E> = (Vec<bool>, Vec<Vec<Coordinates<E>>>, Vec<Coordinates<E>>);
+        let (domain, bases, random_base) = Parameters::<E>::deserialize(deserializer)?;
+
+        // Ensure the domain is padded to the expected size in bits.
+        let max_bits = E::BaseField::size_in_data_bits() - 64; // 64 bits encode the length.
+        if domain.len() != max_bits {
+            return Err(de::Error::custom(format!(
+                "Expected a {max_bits}-bit BHP domain, found {} bits",
+                domain.len()
+            )));
+        }
+        // Ensure the number of windows and chunks match the const generics.
+        if bases.len() != NUM_WINDOWS as usize {
+            return Err(de::Error::custom(format!("Expected {NUM_WINDOWS} BHP windows, found {}", bases.len())));
+        }
+        if let Some(window) = bases.iter().find(|window| window.len() != WINDOW_SIZE as usize) {
+            return Err(de::Error::custom(format!(
+                "Expected a BHP window size of {WINDOW_SIZE}, found {}",
+                window.len()
+            )));
+        }
+        // Ensure the number of random base powers matches the scalar field size.
+        let num_powers = console::Scalar::<E::Network>::size_in_bits();
+        if random_base.len() != num_powers {
+            return Err(de::Error::custom(format!(
+                "Expected {num_powers} BHP random bases, found {}",
+                random_base.len()
+            )));
+        }
+
+        // Recovers a group element from the given coordinates, ensuring it is in the prime-order subgroup.
+        let recover = |(x, y): Coordinates<E>| match console::Group::<E::Network>::from_x_coordinate(x) {
+            Ok(group) if group.to_y_coordinate() == y => Ok(group),
+            _ => Err(de::Error::custom(format!("Invalid BHP base with coordinates ({x}, {y})"))),
+        };
+        let bases = bases
+            .into_iter()
+            .map(|window| window.into_iter().map(recover).collect::<Result<Vec<_>, _>>())
+            .collect::<Result<Vec<_>, _>>()?;
+        let random_base = random_base.into_iter().map(recover).collect::<Result<Vec<_>, _>>()?;
+
+        // Initialize the BHP hasher with the bases, ensuring each base is non-degenerate.
+        let hasher = BHPHasher::<E, NUM_WINDOWS, WINDOW_SIZE>::try_from_bases(&bases, &random_base)
+            .map_err(|error| de::Error::custom(error.to_string()))?;
+        // Initialize the domain.
+        let domain = Vec::constant(domain);
+
+        Ok(Self { domain, hasher, native: None })
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+    const DOMAIN: &str = "BHPCircuit0";
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP256::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let expected = BHP256::<Circuit>::constant(native);
+
+        // Serialize and deserialize the hasher.
+        let candidate: BHP256<Circuit> = serde_json::from_str(&serde_json::to_string(&expected)?)?;
+
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random input.
+            let input: Vec<Boolean<_>> =
+                Inject::new(Mode::Private, (0..261).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>());
+            // Ensure the deserialized hasher produces identical digests.
+            assert_eq!(expected.hash_field(&input).eject_value(), candidate.hash_field(&input).eject_value());
+        }
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_deserialize_rejects_mismatched_sizes() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP256::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let string = serde_json::to_string(&BHP256::<Circuit>::constant(native))?;
+
+        // Ensure the parameters cannot be deserialized with a different number of windows or window size.
+        assert!(serde_json::from_str::<BHP<Circuit, 4, 57>>(&string).is_err());
+        assert!(serde_json::from_str::<BHP<Circuit, 3, 56>>(&string).is_err());
+        Circuit::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_deserialize_rejects_invalid_bases() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP256::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let mut value = serde_json::to_value(BHP256::<Circuit>::constant(native))?;
+
+        // Tamper with the y-coordinate of the first base.
+        value[1][0][0][1] = value[1][0][1][1].clone();
+        assert!(serde_json::from_value::<BHP256<Circuit>>(value).is_err());
+        Circuit::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_deserialize_rejects_identity_base() -> Result<()> {
+        // Initialize BHP.
+        let native = console::BHP256::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let mut value = serde_json::to_value(BHP256::<Circuit>::constant(native))?;
+
+        // Replace the first base with the identity, which is a valid point in the subgroup.
+        let identity = console::Group::<<Circuit as Environment>::Network>::zero();
+        value[1][0][0] = serde_json::to_value(identity.to_xy_coordinates())?;
+
+        // Ensure the identity base is an error, instead of halting.
+        let result = std::panic::catch_unwind(|| serde_json::from_value::<BHP256<Circuit>>(value));
+        let error = result.expect("Deserialization must not halt").err().map(|error| error.to_string());
+        assert_eq!(Some("The BHP base 0 in window 0 is the identity".to_string()), error);
+        Circuit::reset();
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A stateful BHP transcript, which appends an incrementing counter to the input of each absorb,
+/// so that absorbing identical inputs produces distinct digests.
+pub struct BHPTranscript<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> {
+    /// The BHP used to hash each absorbed input.
+    bhp: BHP<E, NUM_WINDOWS, WINDOW_SIZE>,
+    /// The number of inputs absorbed so far.
+    counter: u64,
+}
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPTranscript<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Initializes a new BHP transcript with the given BHP, starting from a counter of `0`.
+    pub const fn new(bhp: BHP<E, NUM_WINDOWS, WINDOW_SIZE>) -> Self {
+        Self { bhp, counter: 0 }
+    }
+
+    /// Returns the number of inputs absorbed so far, which is the counter of the next absorb.
+    pub const fn counter(&self) -> u64 {
+        self.counter
+    }
+
+    /// Returns the BHP hash of the given input, appended with the counter, and increments the counter.
+    ///
+    /// The preimage is `INPUT || COUNTER`, where `COUNTER` is the 64 little-endian bits of the constant counter.
+    pub fn absorb(&mut self, input: &[Boolean<E>]) -> Field<E> {
+        // Initialize a vector for the hash preimage.
+        let mut preimage = Vec::with_capacity(input.len() + 64);
+        // Append the counter to the input.
+        preimage.extend_from_slice(input);
+        U64::constant(console::U64::new(self.counter)).write_bits_le(&mut preimage);
+        // Increment the counter.
+        self.counter = match self.counter.checked_add(1) {
+            Some(counter) => counter,
+            None => E::halt("The BHP transcript counter overflowed"),
+        };
+        // Hash the preimage.
+        self.bhp.hash_field(&preimage)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "BHPCircuit0";
+
+    #[test]
+    fn test_transcript() -> Result<()> {
+        use console::{Hash as H, ToBits as TB};
+
+        // Initialize BHP.
+        let native = console::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let mut transcript = BHPTranscript::new(BHP512::<Circuit>::constant(native.clone()));
+
+            // Sample a sequence of inputs, where the first two inputs are identical.
+            let first = (0..256).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+            let inputs = [first.clone(), first, (0..100).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>()];
+
+            let mut digests = Vec::with_capacity(inputs.len());
+            for (counter, input) in inputs.iter().enumerate() {
+                // Compute the expected digest of the native transcript.
+                let mut preimage = input.clone();
+                console::U64::<<Circuit as Environment>::Network>::new(counter as u64).write_bits_le(&mut preimage);
+                let expected = native.hash(&preimage)?;
+
+                // Absorb the input into the transcript.
+                assert_eq!(counter as u64, transcript.counter());
+                let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input.clone());
+                let candidate = transcript.absorb(&circuit_input);
+                assert_eq!(expected, candidate.eject_value(), "(mode = {mode}, counter = {counter})");
+                digests.push(candidate.eject_value());
+            }
+            assert_eq!(3, transcript.counter());
+
+            // Ensure the identical absorbs yield distinct digests.
+            assert_ne!(digests[0], digests[1]);
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+}
@@ -143,8 +143,8 @@ mod tests {
 
     #[test]
     fn test_hash_children_bhp512_constant() -> Result<()> {
-        check_hash_children!(BHP512, Constant, 2, (1599, 0, 0, 0))?;
-        check_hash_children!(BHP512, Constant, 3, (2792, 0, 0, 0))
+        check_hash_children!(BHP512, Constant, 2, (507, 0, 0, 0))?;
+        check_hash_children!(BHP512, Constant, 3, (760, 0, 0, 0))
     }
 
     #[test]
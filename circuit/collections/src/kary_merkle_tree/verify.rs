@@ -273,7 +273,7 @@ mod tests {
 
     #[test]
     fn test_verify_bhp512_constant() -> Result<()> {
-        check_verify!(BHP1024, BHP512, Constant, 10, 4, 1024, (39234, 0, 0, 0))
+        check_verify!(BHP1024, BHP512, Constant, 10, 4, 1024, (13704, 0, 0, 0))
     }
 
     #[test]
@@ -98,7 +98,7 @@ mod tests {
 
     #[test]
     fn test_hash_leaf_bhp1024_constant() -> Result<()> {
-        check_hash_leaf!(BHP1024, Constant, 1024, (1791, 0, 0, 0))
+        check_hash_leaf!(BHP1024, Constant, 1024, (1, 0, 0, 0))
     }
 
     #[test]
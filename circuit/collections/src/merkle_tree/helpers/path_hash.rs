@@ -33,13 +33,7 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> PathHash<E> f
 
     /// Returns the hash of the given child nodes.
     fn hash_children(&self, left: &Self::Hash, right: &Self::Hash) -> Self::Hash {
-        let mut input = Vec::new();
-        // Prepend the nodes with a `true` bit.
-        input.push(Boolean::constant(true));
-        left.write_bits_le(&mut input);
-        right.write_bits_le(&mut input);
-        // Hash the input.
-        Hash::hash(self, &input)
+        self.merge(left, right)
     }
 }
 
@@ -101,7 +95,7 @@ mod tests {
 
     #[test]
     fn test_hash_children_bhp512_constant() -> Result<()> {
-        check_hash_children!(BHP512, Constant, (1599, 0, 0, 0))
+        check_hash_children!(BHP512, Constant, (507, 0, 0, 0))
     }
 
     #[test]
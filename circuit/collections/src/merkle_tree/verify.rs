@@ -146,7 +146,7 @@ mod tests {
 
     #[test]
     fn test_verify_bhp512_constant() -> Result<()> {
-        check_verify!(BHP1024, BHP512, Constant, 32, 1024, (52960, 0, 0, 0))
+        check_verify!(BHP1024, BHP512, Constant, 32, 1024, (16226, 0, 0, 0))
     }
 
     #[test]
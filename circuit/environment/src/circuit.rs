@@ -14,7 +14,10 @@
 
 use crate::{helpers::Constraint, Mode, *};
 
-use core::{cell::RefCell, fmt};
+use core::{
+    cell::{Cell, RefCell},
+    fmt,
+};
 use std::rc::Rc;
 
 type Field = <console::Testnet3 as console::Environment>::Field;
@@ -22,6 +25,9 @@ type Field = <console::Testnet3 as console::Environment>::Field;
 thread_local! {
     pub(super) static CIRCUIT: Rc<RefCell<R1CS<Field>>> = Rc::new(RefCell::new(R1CS::new()));
     pub(super) static IN_WITNESS: Rc<RefCell<bool>> = Rc::new(RefCell::new(false));
+    pub(super) static FAILURES: Rc<RefCell<Option<Vec<String>>>> = Rc::new(RefCell::new(None));
+    pub(super) static IS_COLLECTING: Cell<bool> = Cell::new(false);
+    pub(super) static CONSTRAINT_LIMIT: Rc<RefCell<Option<u64>>> = Rc::new(RefCell::new(None));
     pub(super) static ZERO: LinearCombination<Field> = LinearCombination::zero();
     pub(super) static ONE: LinearCombination<Field> = LinearCombination::one();
 }
@@ -147,8 +153,27 @@ impl Environment for Circuit {
                     let (a, b, c) = constraint();
                     let (a, b, c) = (a.into(), b.into(), c.into());
 
+                    // If the environment is in enforcement-collection mode, record the constraint if it fails.
+                    // Note: The flag is checked first, so the failures are only borrowed while collecting.
+                    let is_collecting = IS_COLLECTING.with(|is_collecting| is_collecting.get());
+                    if is_collecting && a.value() * b.value() != c.value() {
+                        let scope = (**circuit).borrow().scope();
+                        FAILURES.with(|failures| {
+                            if let Some(failures) = &mut *(**failures).borrow_mut() {
+                                failures.push(format!(
+                                    "Failed constraint at {scope}:\n\t({} * {}) != {}",
+                                    a.value(),
+                                    b.value(),
+                                    c.value()
+                                ));
+                            }
+                        });
+                    }
+
                     // Ensure the constraint is not comprised of constants.
                     match a.is_constant() && b.is_constant() && c.is_constant() {
+                        // In enforcement-collection mode, a failing constant constraint has already been recorded.
+                        true if is_collecting => (),
                         true => {
                             // Evaluate the constant constraint.
                             assert_eq!(
@@ -166,6 +191,16 @@ impl Environment for Circuit {
                             // }
                         }
                         false => {
+                            // Ensure the constraint limit, if one is set, is not exceeded.
+                            if let Some(limit) = CONSTRAINT_LIMIT.with(|limit| *(**limit).borrow()) {
+                                if (**circuit).borrow().num_constraints() >= limit {
+                                    Self::halt(format!(
+                                        "Surpassed the constraint limit of {limit} at {}",
+                                        (**circuit).borrow().scope()
+                                    ))
+                                }
+                            }
+
                             // Construct the constraint object.
                             let constraint = Constraint((**circuit).borrow().scope(), a, b, c);
                             // Append the constraint.
@@ -214,6 +249,11 @@ impl Environment for Circuit {
         CIRCUIT.with(|circuit| (**circuit).borrow().num_nonzeros())
     }
 
+    /// Returns the epoch of the circuit, which changes each time the circuit is reset.
+    fn reset_epoch() -> u64 {
+        CIRCUIT.with(|circuit| (**circuit).borrow().epoch())
+    }
+
     /// Returns the number of constants for the current scope.
     fn num_constants_in_scope() -> u64 {
         CIRCUIT.with(|circuit| (**circuit).borrow().num_constants_in_scope())
@@ -317,6 +357,56 @@ impl Environment for Circuit {
     }
 }
 
+impl Circuit {
+    /// Sets the maximum number of constraints in the environment, or removes the limit if `None`.
+    ///
+    /// When set, adding a constraint beyond the limit halts with a clear message, so that misuse
+    /// (e.g. unbounded recursion) fails fast instead of exhausting memory. The limit persists across `reset`.
+    pub fn set_constraint_limit(limit: Option<u64>) {
+        CONSTRAINT_LIMIT.with(|constraint_limit| *(**constraint_limit).borrow_mut() = limit);
+    }
+
+    /// Returns the maximum number of constraints in the environment, if one is set.
+    pub fn constraint_limit() -> Option<u64> {
+        CONSTRAINT_LIMIT.with(|constraint_limit| *(**constraint_limit).borrow())
+    }
+
+    /// Runs the given logic in enforcement-collection mode, returning its output along with
+    /// a description (including the scope) of every enforcement that failed within the logic.
+    ///
+    /// Unlike the default mode, a failing constant enforcement is recorded instead of halting,
+    /// which is useful for debugging an unsatisfiable circuit. Note that `halt` is unaffected.
+    pub fn collect_failures<Fn, Output>(logic: Fn) -> (Output, Vec<String>)
+    where
+        Fn: FnOnce() -> Output,
+    {
+        /// Restores the previous mode of the environment when dropped, even if the logic unwinds.
+        struct Restore {
+            previous: Option<Vec<String>>,
+            was_collecting: bool,
+        }
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                IS_COLLECTING.with(|is_collecting| is_collecting.set(self.was_collecting));
+                FAILURES.with(|failures| *(**failures).borrow_mut() = self.previous.take());
+            }
+        }
+
+        // Set the entire environment to enforcement-collection mode, saving any outer collection.
+        let previous = FAILURES.with(|failures| (**failures).replace(Some(Vec::new())));
+        let was_collecting = IS_COLLECTING.with(|is_collecting| is_collecting.replace(true));
+        let _restore = Restore { previous, was_collecting };
+
+        // Run the logic.
+        let output = logic();
+
+        // Take the failures collected by the logic, before the guard restores the previous mode.
+        let collected = FAILURES.with(|failures| (**failures).borrow_mut().take()).unwrap_or_default();
+        (output, collected)
+    }
+}
+
 impl fmt::Display for Circuit {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         CIRCUIT.with(|circuit| write!(f, "{}", (**circuit).borrow()))
@@ -358,6 +448,65 @@ mod tests {
         println!("{output}");
     }
 
+    #[test]
+    fn test_collect_failures() {
+        let one = snarkvm_console_types::Field::<<Circuit as Environment>::Network>::one();
+
+        let ((), failures) = Circuit::collect_failures(|| {
+            // Add a satisfied constraint, a failing private constraint, and a failing constant constraint.
+            let a = Field::<Circuit>::new(Mode::Private, one);
+            Circuit::scope("satisfied", || Circuit::assert_eq(&a, &a));
+            Circuit::scope("private", || Circuit::assert_eq(&a, Field::<Circuit>::new(Mode::Private, one + one)));
+            Circuit::scope("constant", || Circuit::assert_eq(Field::<Circuit>::one(), Field::<Circuit>::zero()));
+        });
+
+        // Ensure both failures are reported in order, without halting.
+        assert_eq!(2, failures.len());
+        assert!(failures[0].contains("private"));
+        assert!(failures[1].contains("constant"));
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+
+        // Ensure the collection mode is restored, so a failing constant constraint halts again.
+        let result = std::panic::catch_unwind(|| Circuit::assert_eq(Field::<Circuit>::one(), Field::<Circuit>::zero()));
+        assert!(result.is_err());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_collect_failures_restores_on_halt() {
+        // Ensure the collection mode is restored when the logic halts.
+        let result = std::panic::catch_unwind(|| {
+            Circuit::collect_failures(|| Circuit::halt::<_, ()>("Halted while collecting failures"))
+        });
+        assert!(result.is_err());
+
+        // Ensure a failing constant constraint halts again, instead of being recorded.
+        let result = std::panic::catch_unwind(|| Circuit::assert_eq(Field::<Circuit>::one(), Field::<Circuit>::zero()));
+        assert!(result.is_err());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_assert_constraint_free() {
+        let one = snarkvm_console_types::Field::<<Circuit as Environment>::Network>::one();
+
+        // Ensure constant logic and witness allocations pass through, returning the output.
+        let output = Circuit::assert_constraint_free(|| {
+            let a = Field::<Circuit>::constant(one);
+            let _b = Field::<Circuit>::new(Mode::Private, one);
+            a.square()
+        });
+        assert_eq!(one, output.eject_value());
+
+        // Ensure logic adding a constraint halts.
+        let result = std::panic::catch_unwind(|| {
+            Circuit::assert_constraint_free(|| Field::<Circuit>::new(Mode::Private, one).square())
+        });
+        assert!(result.is_err());
+        Circuit::reset();
+    }
+
     #[test]
     fn test_circuit_scope() {
         Circuit::scope("test_circuit_scope", || {
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{witness_mode, Assignment, Inject, LinearCombination, Mode, Variable, R1CS};
+use crate::{witness_mode, Assignment, ConstraintSnapshot, Inject, LinearCombination, Mode, Variable, R1CS};
 use snarkvm_curves::AffineCurve;
 use snarkvm_fields::traits::*;
 
@@ -124,6 +124,9 @@ pub trait Environment: 'static + Copy + Clone + fmt::Debug + fmt::Display + Eq +
     /// Returns the number of nonzeros in the entire circuit.
     fn num_nonzeros() -> (u64, u64, u64);
 
+    /// Returns the epoch of the environment, which changes each time the environment is reset.
+    fn reset_epoch() -> u64;
+
     /// Returns a tuple containing the number of constants, public variables, private variables, constraints, and nonzeros in the entire environment.
     fn count() -> (u64, u64, u64, u64, (u64, u64, u64)) {
         (Self::num_constants(), Self::num_public(), Self::num_private(), Self::num_constraints(), Self::num_nonzeros())
@@ -155,6 +158,43 @@ pub trait Environment: 'static + Copy + Clone + fmt::Debug + fmt::Display + Eq +
         )
     }
 
+    /// Returns a snapshot of the current counts of the environment, to measure the cost of an arbitrary
+    /// region of code (e.g. several hashes) with `ConstraintSnapshot::diff`, without nesting scopes.
+    fn snapshot() -> ConstraintSnapshot<Self> {
+        ConstraintSnapshot::new()
+    }
+
+    /// Runs the given logic, and halts if it adds any constraints to the environment
+    /// (e.g. to assert that a gadget on constant inputs is free).
+    fn assert_constraint_free<Fn, Output>(logic: Fn) -> Output
+    where
+        Fn: FnOnce() -> Output,
+    {
+        let num_constraints = Self::num_constraints();
+        let output = logic();
+        let num_added = Self::num_constraints() - num_constraints;
+        if num_added != 0 {
+            Self::halt(format!("Expected the logic to be constraint-free, found {num_added} constraints"))
+        }
+        output
+    }
+
+    /// Runs the given logic, and returns its output along with the dependency graph of the constraints
+    /// it added, in the DOT format (see `R1CS::to_dot`).
+    #[cfg(feature = "debug-graph")]
+    fn capture_dot_graph<Fn, Output>(logic: Fn) -> (Output, String)
+    where
+        Fn: FnOnce() -> Output,
+    {
+        let num_constraints = Self::num_constraints() as usize;
+        let output = logic();
+        // Eject the circuit to inspect its constraints, and inject it back unchanged.
+        let r1cs = Self::eject_r1cs_and_reset();
+        let dot = r1cs.to_dot(num_constraints);
+        Self::inject_r1cs(r1cs);
+        (output, dot)
+    }
+
     /// Halts the program from further synthesis, evaluation, and execution in the current environment.
     fn halt<S: Into<String>, T>(message: S) -> T {
         <Self::Network as console::Environment>::halt(message)
@@ -147,6 +147,112 @@ impl<F: PrimeField> Assignment<F> {
     }
 }
 
+impl<F: PrimeField> Assignment<F> {
+    /// Returns the sparse R1CS matrices and the full variable assignment of the assignment.
+    ///
+    /// See `AssignmentMatrices` for the layout of the columns.
+    pub fn to_matrices(&self) -> AssignmentMatrices<F> {
+        let num_public = self.public.len();
+
+        // Returns the column of the given variable in `z`.
+        let column = |variable: &AssignmentVariable<F>| match variable {
+            AssignmentVariable::Constant(_) => {
+                unreachable!("The assignment by definition cannot have constant variables in the terms")
+            }
+            AssignmentVariable::Public(index) => *index as usize,
+            AssignmentVariable::Private(index) => num_public + *index as usize,
+        };
+
+        // Appends the sparse triples of the given linear combination, as the given row of a matrix.
+        let append_row = |matrix: &mut Vec<(usize, usize, F)>, row: usize, lc: &AssignmentLC<F>| {
+            if !lc.constant.is_zero() {
+                matrix.push((row, 0, lc.constant));
+            }
+            matrix.extend(lc.terms.iter().map(|(variable, coefficient)| (row, column(variable), *coefficient)));
+        };
+
+        let (mut a, mut b, mut c) = (Vec::new(), Vec::new(), Vec::new());
+        for (row, (a_lc, b_lc, c_lc)) in self.constraints.iter().enumerate() {
+            append_row(&mut a, row, a_lc);
+            append_row(&mut b, row, b_lc);
+            append_row(&mut c, row, c_lc);
+        }
+
+        // Construct `z` as the public values (in index order), followed by the private values (in index order).
+        let z = self.public.iter().chain(&self.private).map(|(_, value)| *value).collect();
+
+        AssignmentMatrices { a, b, c, z, num_public, num_constraints: self.constraints.len() }
+    }
+}
+
+/// The sparse R1CS matrices `A`, `B`, and `C` of an assignment, along with the full variable assignment `z`,
+/// for exporting the constraints to another proving backend.
+///
+/// Each matrix is a vector of `(row, column, coefficient)` triples, with one row per constraint.
+/// The columns index into `z = [ PUBLIC || PRIVATE ]`, where the public and private variables are each in index order.
+/// Column `0` is the public variable `1`, which also carries the constant term of each linear combination.
+/// The assignment is satisfied if and only if `(A * z) ∘ (B * z) == (C * z)`, where `∘` is the entrywise product.
+#[derive(Clone, Debug)]
+pub struct AssignmentMatrices<F: PrimeField> {
+    a: Vec<(usize, usize, F)>,
+    b: Vec<(usize, usize, F)>,
+    c: Vec<(usize, usize, F)>,
+    z: Vec<F>,
+    num_public: usize,
+    num_constraints: usize,
+}
+
+impl<F: PrimeField> AssignmentMatrices<F> {
+    /// Returns the sparse triples of the `A` matrix.
+    pub fn a(&self) -> &[(usize, usize, F)] {
+        &self.a
+    }
+
+    /// Returns the sparse triples of the `B` matrix.
+    pub fn b(&self) -> &[(usize, usize, F)] {
+        &self.b
+    }
+
+    /// Returns the sparse triples of the `C` matrix.
+    pub fn c(&self) -> &[(usize, usize, F)] {
+        &self.c
+    }
+
+    /// Returns the full variable assignment `z`.
+    pub fn z(&self) -> &[F] {
+        &self.z
+    }
+
+    /// Returns a mutable reference to the full variable assignment `z`.
+    pub fn z_mut(&mut self) -> &mut [F] {
+        &mut self.z
+    }
+
+    /// Returns the number of public variables, which are the first columns of `z`.
+    pub const fn num_public(&self) -> usize {
+        self.num_public
+    }
+
+    /// Returns the number of rows, which is the number of constraints.
+    pub const fn num_constraints(&self) -> usize {
+        self.num_constraints
+    }
+
+    /// Returns `true` if `(A * z) ∘ (B * z) == (C * z)`.
+    pub fn is_satisfied(&self) -> bool {
+        // Returns the product of the given matrix with `z`.
+        let multiply = |matrix: &[(usize, usize, F)]| {
+            let mut product = vec![F::zero(); self.num_constraints];
+            for (row, column, coefficient) in matrix {
+                product[*row] += *coefficient * self.z[*column];
+            }
+            product
+        };
+        let (a, b, c) = (multiply(&self.a), multiply(&self.b), multiply(&self.c));
+        a.iter().zip(&b).zip(&c).all(|((a, b), c)| *a * b == *c)
+    }
+}
+
 impl<F: PrimeField> snarkvm_algorithms::r1cs::ConstraintSynthesizer<F> for Assignment<F> {
     /// Synthesizes the constraints from the environment into a `snarkvm_algorithms::r1cs`-compliant constraint system.
     fn generate_constraints<CS: snarkvm_algorithms::r1cs::ConstraintSystem<F>>(
@@ -315,6 +421,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_to_matrices() {
+        let _candidate_output = create_example_circuit::<Circuit>();
+        let assignment = Circuit::eject_assignment_and_reset();
+
+        // Ensure the matrices have one row per constraint, and one column per variable.
+        let mut matrices = assignment.to_matrices();
+        assert_eq!(assignment.num_constraints() as usize, matrices.num_constraints());
+        assert_eq!(assignment.num_public() as usize, matrices.num_public());
+        assert_eq!((assignment.num_public() + assignment.num_private()) as usize, matrices.z().len());
+        assert_eq!(Fr::one(), matrices.z()[0]);
+
+        // Ensure the number of triples matches the number of nonzeros.
+        let (a, b, c) = assignment.num_nonzeros();
+        assert_eq!((a, b, c), (matrices.a().len() as u64, matrices.b().len() as u64, matrices.c().len() as u64));
+
+        // Ensure the matrices are satisfied by the assignment.
+        assert!(matrices.is_satisfied());
+
+        // Ensure the matrices are not satisfied by a tampered assignment.
+        let last = matrices.z().len() - 1;
+        matrices.z_mut()[last] += Fr::one();
+        assert!(!matrices.is_satisfied());
+    }
+
     #[test]
     fn test_varuna() {
         let _candidate_output = create_example_circuit::<Circuit>();
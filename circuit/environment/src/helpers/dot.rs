@@ -0,0 +1,104 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{prelude::*, R1CS};
+use snarkvm_fields::PrimeField;
+
+use std::{collections::BTreeSet, fmt::Write};
+
+impl<F: PrimeField> R1CS<F> {
+    /// Returns the dependency graph of the constraints from the given index onwards, in the DOT format.
+    ///
+    /// Each public and private variable is a node, each constraint is a node labeled with its scope,
+    /// and each variable has an edge into every constraint whose `A`, `B`, or `C` term uses it.
+    /// Constants are folded into the terms, so they are not nodes.
+    pub fn to_dot(&self, first_constraint: usize) -> String {
+        let node = |variable: &Variable<F>| match variable.is_public() {
+            true => format!("public_{}", variable.index()),
+            false => format!("private_{}", variable.index()),
+        };
+
+        let mut variables = BTreeSet::new();
+        let mut edges = String::new();
+        let mut constraints = String::new();
+        for (index, constraint) in self.to_constraints().iter().enumerate().skip(first_constraint) {
+            let label = escape_label(&constraint.0);
+            writeln!(constraints, "    constraint_{index} [shape=box, label=\"{index}: {label}\"];").unwrap();
+            let (a, b, c) = constraint.to_terms();
+            for (term, lc) in [("A", a), ("B", b), ("C", c)] {
+                for (variable, _) in lc.to_terms() {
+                    variables.insert(node(variable));
+                    writeln!(edges, "    {} -> constraint_{index} [label=\"{term}\"];", node(variable)).unwrap();
+                }
+            }
+        }
+
+        let mut output = String::from("digraph R1CS {\n");
+        variables.iter().for_each(|variable| writeln!(output, "    {variable} [shape=ellipse];").unwrap());
+        output += &constraints;
+        output += &edges;
+        output += "}\n";
+        output
+    }
+}
+
+/// Returns the given label with its quotes and backslashes escaped, for use in a quoted DOT string.
+fn escape_label(label: &str) -> String {
+    let mut escaped = String::with_capacity(label.len());
+    for character in label.chars() {
+        if matches!(character, '"' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(character);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use snarkvm_circuit::prelude::*;
+
+    #[test]
+    fn test_to_dot() {
+        let one = snarkvm_console_types::Field::<<Circuit as Environment>::Network>::one();
+
+        // Enforce `a * b == c`, with a public `a` and private `b` and `c`.
+        let a = Field::<Circuit>::new(Mode::Public, one);
+        let b = Field::<Circuit>::new(Mode::Private, one);
+        let _c = Circuit::scope("product", || &a * &b);
+
+        let r1cs = Circuit::eject_r1cs_and_reset();
+        let dot = r1cs.to_dot(0);
+        assert!(dot.starts_with("digraph R1CS {\n") && dot.ends_with("}\n"));
+        assert!(dot.contains("constraint_0 [shape=box, label=\"0: product\"];"));
+        assert!(dot.contains("public_1 -> constraint_0 [label=\"A\"];"));
+        assert!(dot.contains("private_0 -> constraint_0 [label=\"B\"];"));
+        assert!(dot.contains("private_1 -> constraint_0 [label=\"C\"];"));
+
+        // Ensure the graph from beyond the last constraint is empty.
+        assert_eq!("digraph R1CS {\n}\n", r1cs.to_dot(1));
+    }
+
+    #[test]
+    fn test_to_dot_escapes_labels() {
+        let one = snarkvm_console_types::Field::<<Circuit as Environment>::Network>::one();
+
+        // Enforce a constraint in a scope containing a quote and a backslash.
+        let a = Field::<Circuit>::new(Mode::Private, one);
+        let _b = Circuit::scope(r#"say "hi" \ bye"#, || a.square());
+
+        let dot = Circuit::eject_r1cs_and_reset().to_dot(0);
+        assert!(dot.contains(r#"constraint_0 [shape=box, label="0: say \"hi\" \\ bye"];"#), "{dot}");
+    }
+}
@@ -23,6 +23,9 @@ pub use constraint::*;
 
 pub(super) mod converter;
 
+#[cfg(feature = "debug-graph")]
+mod dot;
+
 pub mod count;
 pub use count::*;
 
@@ -41,5 +44,8 @@ pub use variable::*;
 pub mod r1cs;
 pub use r1cs::*;
 
+pub mod snapshot;
+pub use snapshot::*;
+
 pub mod updatable_count;
 pub use updatable_count::*;
@@ -18,10 +18,16 @@ use crate::{
 };
 use snarkvm_fields::PrimeField;
 
-use std::rc::Rc;
+use std::{
+    rc::Rc,
+    sync::atomic::{AtomicU64, Ordering},
+};
 
 pub type Scope = String;
 
+/// The epoch of the next constraint system, which is unique to each constraint system.
+static NEXT_EPOCH: AtomicU64 = AtomicU64::new(0);
+
 #[derive(Debug)]
 pub struct R1CS<F: PrimeField> {
     constants: Vec<Variable<F>>,
@@ -30,6 +36,7 @@ pub struct R1CS<F: PrimeField> {
     constraints: Vec<Rc<Constraint<F>>>,
     counter: Counter<F>,
     nonzeros: (u64, u64, u64),
+    epoch: u64,
 }
 
 impl<F: PrimeField> R1CS<F> {
@@ -42,6 +49,7 @@ impl<F: PrimeField> R1CS<F> {
             constraints: Default::default(),
             counter: Default::default(),
             nonzeros: (0, 0, 0),
+            epoch: NEXT_EPOCH.fetch_add(1, Ordering::Relaxed),
         }
     }
 
@@ -126,6 +134,11 @@ impl<F: PrimeField> R1CS<F> {
         self.constraints.len() as u64
     }
 
+    /// Returns the epoch of the constraint system, which is unique to each new constraint system.
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
     /// Returns the number of nonzeros in the constraint system.
     pub fn num_nonzeros(&self) -> (u64, u64, u64) {
         self.nonzeros
@@ -0,0 +1,144 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Environment;
+
+use core::{marker::PhantomData, ops::Add};
+
+/// The number of constants, public variables, private variables, constraints, and nonzeros in a region of a circuit.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConstraintCount {
+    /// The number of constants.
+    pub num_constants: u64,
+    /// The number of public variables.
+    pub num_public: u64,
+    /// The number of private variables.
+    pub num_private: u64,
+    /// The number of constraints.
+    pub num_constraints: u64,
+    /// The number of nonzeros in the `A`, `B`, and `C` matrices.
+    pub num_nonzeros: (u64, u64, u64),
+}
+
+impl Add for ConstraintCount {
+    type Output = Self;
+
+    /// Returns the sum of the two counts.
+    fn add(self, other: Self) -> Self::Output {
+        Self {
+            num_constants: self.num_constants + other.num_constants,
+            num_public: self.num_public + other.num_public,
+            num_private: self.num_private + other.num_private,
+            num_constraints: self.num_constraints + other.num_constraints,
+            num_nonzeros: (
+                self.num_nonzeros.0 + other.num_nonzeros.0,
+                self.num_nonzeros.1 + other.num_nonzeros.1,
+                self.num_nonzeros.2 + other.num_nonzeros.2,
+            ),
+        }
+    }
+}
+
+/// A snapshot of the counts of an environment, to measure the cost of an arbitrary region of code
+/// without nesting scopes. The region ends when `diff` is called.
+#[derive(Copy, Clone, Debug)]
+pub struct ConstraintSnapshot<E: Environment> {
+    /// The counts of the environment when the snapshot was taken.
+    count: (u64, u64, u64, u64, (u64, u64, u64)),
+    /// The reset epoch of the environment when the snapshot was taken.
+    epoch: u64,
+    /// PhantomData.
+    _phantom: PhantomData<E>,
+}
+
+impl<E: Environment> ConstraintSnapshot<E> {
+    /// Returns a snapshot of the current counts of the environment.
+    pub fn new() -> Self {
+        Self { count: E::count(), epoch: E::reset_epoch(), _phantom: PhantomData }
+    }
+
+    /// Returns the counts added to the environment since the snapshot was taken.
+    ///
+    /// This method halts if the environment was reset after the snapshot was taken.
+    pub fn diff(&self) -> ConstraintCount {
+        // Ensure the environment was not reset after the snapshot was taken.
+        if E::reset_epoch() != self.epoch {
+            E::halt("The environment was reset after the constraint snapshot was taken")
+        }
+
+        let (constants, public, private, constraints, (a, b, c)) = self.count;
+        let (now_constants, now_public, now_private, now_constraints, (now_a, now_b, now_c)) = E::count();
+
+        ConstraintCount {
+            num_constants: now_constants - constants,
+            num_public: now_public - public,
+            num_private: now_private - private,
+            num_constraints: now_constraints - constraints,
+            num_nonzeros: (now_a - a, now_b - b, now_c - c),
+        }
+    }
+}
+
+impl<E: Environment> Default for ConstraintSnapshot<E> {
+    /// Returns a snapshot of the current counts of the environment.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use snarkvm_circuit::prelude::*;
+
+    #[test]
+    fn test_snapshot_diff() {
+        let one = snarkvm_console_types::Field::<<Circuit as Environment>::Network>::one();
+
+        // Ensure the snapshot measures the region of code after it, and not before it.
+        let a = Field::<Circuit>::new(Mode::Private, one);
+        let snapshot = Circuit::snapshot();
+        let _b = Field::<Circuit>::constant(one);
+        let _c = &a * Field::<Circuit>::new(Mode::Public, one);
+
+        let count = snapshot.diff();
+        assert_eq!(1, count.num_constants);
+        assert_eq!(1, count.num_public);
+        assert_eq!(1, count.num_private);
+        assert_eq!(1, count.num_constraints);
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_snapshot_diff_halts_after_reset() {
+        let one = snarkvm_console_types::Field::<<Circuit as Environment>::Network>::one();
+
+        // Ensure a reset is detected, even if the circuit afterwards exceeds the counts of the snapshot.
+        let _a = Field::<Circuit>::new(Mode::Private, one);
+        let snapshot = Circuit::snapshot();
+        Circuit::reset();
+        let _b = Field::<Circuit>::new(Mode::Private, one).square();
+        let _c = Field::<Circuit>::new(Mode::Private, one);
+
+        let result = std::panic::catch_unwind(|| snapshot.diff());
+        assert!(result.is_err());
+        Circuit::reset();
+
+        // Ensure ejecting and injecting the same circuit is not a reset.
+        let snapshot = Circuit::snapshot();
+        let r1cs = Circuit::eject_r1cs_and_reset();
+        Circuit::inject_r1cs(r1cs);
+        assert_eq!(0, snapshot.diff().num_constraints);
+        Circuit::reset();
+    }
+}
@@ -436,6 +436,11 @@ impl Environment for AleoV0 {
         E::num_nonzeros()
     }
 
+    /// Returns the epoch of the circuit, which changes each time the circuit is reset.
+    fn reset_epoch() -> u64 {
+        E::reset_epoch()
+    }
+
     /// Returns the number of constants for the current scope.
     fn num_constants_in_scope() -> u64 {
         E::num_constants_in_scope()
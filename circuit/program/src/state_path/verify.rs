@@ -225,8 +225,8 @@ mod tests {
 
     #[test]
     fn test_state_path_verify_global_constant() -> Result<()> {
-        check_verify_global(Mode::Constant, true, 106309, 1, 2, 2)?;
-        check_verify_global(Mode::Constant, false, 106309, 1, 2, 2)
+        check_verify_global(Mode::Constant, true, 33690, 1, 2, 2)?;
+        check_verify_global(Mode::Constant, false, 33690, 1, 2, 2)
     }
 
     #[test]
@@ -243,10 +243,10 @@ mod tests {
 
     #[test]
     fn test_state_path_verify_local_constant() -> Result<()> {
-        check_verify_local(Mode::Constant, false, true, 106309, 1, 2, 2)?;
-        check_verify_local(Mode::Constant, false, false, 106309, 1, 2, 2)?;
-        check_verify_local(Mode::Constant, true, true, 106309, 1, 2, 2)?;
-        check_verify_local(Mode::Constant, true, false, 106309, 1, 2, 2)
+        check_verify_local(Mode::Constant, false, true, 33690, 1, 2, 2)?;
+        check_verify_local(Mode::Constant, false, false, 33690, 1, 2, 2)?;
+        check_verify_local(Mode::Constant, true, true, 33690, 1, 2, 2)?;
+        check_verify_local(Mode::Constant, true, false, 33690, 1, 2, 2)
     }
 
     #[test]
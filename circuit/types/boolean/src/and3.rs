@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Boolean<E> {
+    /// Returns `(a AND b AND c)`.
+    ///
+    /// Note: This costs at most 2 constraints, one for each pairwise `AND` of variables.
+    /// In the BHP hasher, fusing the sign bit into `bit_0 AND bit_1` does not reduce constraints,
+    /// as the sign bit is already applied to the y-coordinate in a single custom constraint.
+    pub fn and3(a: &Self, b: &Self, c: &Self) -> Self {
+        a & b & c
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    fn check_and3(mode_a: Mode, mode_b: Mode, mode_c: Mode) {
+        for (a, b, c) in (0..8u8).map(|i| (i & 1 == 1, i & 2 == 2, i & 4 == 4)) {
+            let expected = a & b & c;
+            let candidate_a = Boolean::<Circuit>::new(mode_a, a);
+            let candidate_b = Boolean::<Circuit>::new(mode_b, b);
+            let candidate_c = Boolean::<Circuit>::new(mode_c, c);
+
+            Circuit::scope(format!("{mode_a} {mode_b} {mode_c}"), || {
+                let candidate = Boolean::and3(&candidate_a, &candidate_b, &candidate_c);
+                assert_eq!(expected, candidate.eject_value(), "({a} AND {b} AND {c})");
+                match (mode_a.is_constant(), mode_b.is_constant(), mode_c.is_constant()) {
+                    (true, true, true) => assert_scope!(0, 0, 0, 0),
+                    (false, false, false) => assert_scope!(0, 0, 2, 2),
+                    _ => assert!(Circuit::num_constraints_in_scope() <= 1),
+                }
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_and3() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                for mode_c in [Mode::Constant, Mode::Public, Mode::Private] {
+                    check_and3(mode_a, mode_b, mode_c);
+                }
+            }
+        }
+    }
+}
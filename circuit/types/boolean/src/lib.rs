@@ -19,6 +19,7 @@
 mod helpers;
 
 pub mod and;
+pub mod and3;
 pub mod equal;
 pub mod nand;
 pub mod nor;
@@ -162,6 +162,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_is_less_than_boundaries() {
+        let zero = console::Field::<<Circuit as Environment>::Network>::zero();
+        let max = -console::Field::<<Circuit as Environment>::Network>::one();
+
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                // Check the extremes of the field, including equal values.
+                for (first, second) in [(zero, max), (max, zero), (zero, zero), (max, max)] {
+                    let candidate = Field::<Circuit>::new(mode_a, first).is_less_than(&Field::new(mode_b, second));
+                    assert_eq!(first < second, candidate.eject_value(), "({mode_a}, {mode_b})");
+                    Circuit::reset();
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_constant_is_less_than_constant() {
         run_test(Mode::Constant, Mode::Constant, 0, 0, 0, 0);
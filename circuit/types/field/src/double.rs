@@ -72,6 +72,26 @@ mod tests {
         check_double("Private", Mode::Private, &mut rng);
     }
 
+    #[test]
+    fn test_double_matches_add() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for _ in 0..ITERATIONS {
+                // Sample a random element.
+                let given = Uniform::rand(&mut rng);
+                let candidate = Field::<Circuit>::new(mode, given);
+
+                Circuit::scope(format!("{mode}"), || {
+                    // Ensure `x.double()` is equivalent to `x + x`, and is free.
+                    assert_eq!((&candidate + &candidate).eject_value(), candidate.double().eject_value());
+                    assert_scope!(0, 0, 0, 0);
+                });
+            }
+            Circuit::reset();
+        }
+    }
+
     #[test]
     fn test_0_double() {
         let zero = console::Field::<<Circuit as Environment>::Network>::zero();
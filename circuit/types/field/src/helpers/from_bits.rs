@@ -223,4 +223,23 @@ mod tests {
     fn test_from_bits_be_private() {
         check_from_bits_be(Mode::Private, 0, 0, 252, 253);
     }
+
+    #[test]
+    fn test_from_bits_le_rejects_excess_bits() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for i in 0..ITERATIONS {
+                // Sample a random element, and append a nonzero excess bit to its bits.
+                let expected: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+                let mut given_bits = Field::<Circuit>::new(mode, expected).to_bits_le();
+                given_bits.push(Boolean::new(mode, true));
+
+                // Ensure the excess bit is rejected.
+                let result = std::panic::catch_unwind(|| Field::<Circuit>::from_bits_le(&given_bits));
+                assert!(result.is_err() || !Circuit::is_satisfied(), "{mode} {i}");
+                Circuit::reset();
+            }
+        }
+    }
 }
@@ -18,7 +18,9 @@ impl<E: Environment> Sub<Field<E>> for Field<E> {
     type Output = Self;
 
     fn sub(self, other: Field<E>) -> Self::Output {
-        self - &other
+        let mut result = self;
+        result -= other;
+        result
     }
 }
 
@@ -36,7 +38,9 @@ impl<E: Environment> Sub<Field<E>> for &Field<E> {
     type Output = Field<E>;
 
     fn sub(self, other: Field<E>) -> Self::Output {
-        self - &other
+        let mut result = self.clone();
+        result -= other;
+        result
     }
 }
 
@@ -52,7 +56,7 @@ impl<E: Environment> Sub<&Field<E>> for &Field<E> {
 
 impl<E: Environment> SubAssign<Field<E>> for Field<E> {
     fn sub_assign(&mut self, other: Field<E>) {
-        *self -= &other;
+        *self += -other;
     }
 }
 
@@ -285,4 +289,33 @@ mod tests {
         let candidate = Field::<Circuit>::new(Mode::Private, two) - Field::new(Mode::Private, one);
         assert_eq!(one, candidate.eject_value());
     }
+
+    #[test]
+    fn test_sub_and_neg_reference_variants() {
+        let mut rng = TestRng::default();
+
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                let first = Uniform::rand(&mut rng);
+                let second = Uniform::rand(&mut rng);
+                let a = Field::<Circuit>::new(mode_a, first);
+                let b = Field::<Circuit>::new(mode_b, second);
+
+                // Ensure each reference variant of `Sub` matches the owned form.
+                let expected = a.clone() - b.clone();
+                for candidate in [&a - &b, a.clone() - &b, &a - b.clone()] {
+                    assert_eq!(expected.eject_value(), candidate.eject_value());
+                    assert_eq!(expected.eject_mode(), candidate.eject_mode());
+                }
+                assert_eq!(first - second, expected.eject_value());
+
+                // Ensure the reference variant of `Neg` matches the owned form.
+                let expected = -a.clone();
+                let candidate = -&a;
+                assert_eq!(expected.eject_value(), candidate.eject_value());
+                assert_eq!(expected.eject_mode(), candidate.eject_mode());
+                assert_eq!(-first, expected.eject_value());
+            }
+        }
+    }
 }
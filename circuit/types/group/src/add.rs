@@ -87,6 +87,14 @@ impl<E: Environment> Add<&Self> for Group<E> {
     }
 }
 
+impl<E: Environment> Add<Group<E>> for &Group<E> {
+    type Output = Group<E>;
+
+    fn add(self, other: Group<E>) -> Self::Output {
+        other + self
+    }
+}
+
 impl<E: Environment> Add<&Group<E>> for &Group<E> {
     type Output = Group<E>;
 
@@ -253,4 +261,30 @@ mod tests {
         let candidate_b = first + second;
         assert_eq!(expected, candidate_b.eject_value());
     }
+
+    #[test]
+    fn test_add_variants_match() {
+        let mut rng = TestRng::default();
+
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                for i in 0..ITERATIONS {
+                    let first = Uniform::rand(&mut rng);
+                    let second = Uniform::rand(&mut rng);
+                    let expected = first + second;
+
+                    let a = Group::<Circuit>::new(mode_a, first);
+                    let b = Group::<Circuit>::new(mode_b, second);
+
+                    Circuit::scope(format!("Add variants {mode_a} {mode_b} {i}"), || {
+                        assert_eq!(expected, (a.clone() + b.clone()).eject_value());
+                        assert_eq!(expected, (a.clone() + &b).eject_value());
+                        assert_eq!(expected, (&a + b.clone()).eject_value());
+                        assert_eq!(expected, (&a + &b).eject_value());
+                    });
+                    Circuit::reset();
+                }
+            }
+        }
+    }
 }
@@ -20,6 +20,10 @@ impl<E: Environment> Equal<Self> for Group<E> {
     ///
     /// Returns `true` if `self` and `other` are equal.
     ///
+    /// This method compares both coordinates, as the x-coordinate alone aliases `(x, y)` and `(x, -y)`,
+    /// which are distinct points on the twisted Edwards curve. (Only one of them is in the prime-order subgroup,
+    /// so the aliasing arises for points constructed with `Group::from_xy_coordinates_unchecked`.)
+    ///
     /// This method costs 8 constraints.
     ///
     fn is_equal(&self, other: &Self) -> Self::Output {
@@ -48,6 +52,28 @@ mod tests {
 
     const ITERATIONS: u64 = 100;
 
+    #[test]
+    fn test_is_equal_with_aliased_x_coordinate() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for _ in 0..ITERATIONS {
+                // Sample a random point `(x, y)`, and construct the point `(x, -y)`.
+                let point: console::Group<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+                let (x, y) = (point.to_x_coordinate(), point.to_y_coordinate());
+
+                let a = Group::<Circuit>::new(mode, point);
+                let b = Group::<Circuit>::from_xy_coordinates_unchecked(Field::new(mode, x), Field::new(mode, -y));
+
+                // Ensure the points are not equal, even though their x-coordinates are.
+                assert!(!a.is_equal(&b).eject_value());
+                assert!(a.is_not_equal(&b).eject_value());
+                assert!(a.to_x_coordinate().is_equal(&b.to_x_coordinate()).eject_value());
+                Circuit::reset();
+            }
+        }
+    }
+
     #[test]
     fn test_is_equal() {
         let mut rng = TestRng::default();
@@ -21,5 +21,6 @@ pub mod mul_by_cofactor;
 pub mod to_bits;
 pub mod to_field;
 pub mod to_x_coordinate;
+pub mod to_xy_coordinates;
 pub mod to_y_coordinate;
 pub mod zero;
@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Group<E> {
+    /// Returns the affine x- and y-coordinates of the group element.
+    pub fn to_xy_coordinates(&self) -> (Field<E>, Field<E>) {
+        (self.x.clone(), self.y.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_to_xy_coordinates(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random element.
+            let expected: console::Group<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            let candidate = Group::<Circuit>::new(mode, expected);
+
+            Circuit::scope(&format!("{mode} {i}"), || {
+                let (x, y) = candidate.to_xy_coordinates();
+                assert_eq!(expected.to_x_coordinate(), x.eject_value());
+                assert_eq!(expected.to_y_coordinate(), y.eject_value());
+                assert_scope!(0, 0, 0, 0);
+
+                // Ensure the coordinates round-trip.
+                let (x, y) = candidate.to_xy_coordinates();
+                let point = Group::from_xy_coordinates_unchecked(x, y);
+                assert_eq!(expected, point.eject_value());
+                assert!(point.is_equal(&candidate).eject_value());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_to_xy_coordinates_constant() {
+        check_to_xy_coordinates(Mode::Constant);
+    }
+
+    #[test]
+    fn test_to_xy_coordinates_public() {
+        check_to_xy_coordinates(Mode::Public);
+    }
+
+    #[test]
+    fn test_to_xy_coordinates_private() {
+        check_to_xy_coordinates(Mode::Private);
+    }
+}
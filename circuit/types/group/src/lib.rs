@@ -21,6 +21,7 @@ mod helpers;
 pub mod add;
 pub mod double;
 pub mod equal;
+pub mod msm;
 pub mod mul;
 pub mod neg;
 pub mod sub;
@@ -37,6 +38,12 @@ use snarkvm_circuit_types_boolean::Boolean;
 use snarkvm_circuit_types_field::Field;
 use snarkvm_circuit_types_scalar::Scalar;
 
+/// A group element on the twisted Edwards curve, in affine coordinates `(x, y)`.
+///
+/// There is no projective representation at this layer, as a field division costs 1 constraint in R1CS,
+/// so the affine addition formulas are cheaper than tracking a projective denominator.
+/// Gadgets using other representations internally (e.g. the BHP hash accumulates on the Montgomery curve)
+/// convert back to affine twisted Edwards coordinates on output.
 #[derive(Clone)]
 pub struct Group<E: Environment> {
     x: Field<E>,
@@ -92,6 +99,23 @@ impl<E: Environment> Group<E> {
         // Ensure y^2 * (dx^2 - 1) = (ax^2 - 1).
         E::enforce(|| (first, second, third));
     }
+
+    /// Returns the twisted Edwards curve equation residual of the given coordinates, as follows:
+    /// `a * x^2 + y^2 - 1 - d * x^2 * y^2`
+    /// which is zero if and only if `(x, y)` is on the curve.
+    ///
+    /// This method is a debugging aid, and enforces nothing. When `enforce_on_curve` is given a point
+    /// that is not on the curve, the circuit is unsatisfied by an opaque constraint, whereas the residual
+    /// can be ejected and inspected (e.g. in tests asserting the validity of a BHP digest).
+    pub fn curve_residual(x: &Field<E>, y: &Field<E>) -> Field<E> {
+        let a = Field::constant(console::Field::new(E::EDWARDS_A));
+        let d = Field::constant(console::Field::new(E::EDWARDS_D));
+
+        let x2 = x.square();
+        let y2 = y.square();
+
+        (a * &x2) + &y2 - Field::one() - (d * x2 * y2)
+    }
 }
 
 impl<E: Environment> Group<E> {
@@ -274,6 +298,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_curve_residual() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for _ in 0..ITERATIONS {
+                // Sample a random point, which is on the curve.
+                let point = Group::<Circuit>::new(mode, Uniform::rand(&mut rng));
+                let residual = Group::curve_residual(&point.to_x_coordinate(), &point.to_y_coordinate());
+                assert!(residual.eject_value().is_zero());
+
+                // Perturb the y-coordinate, so the point is not on the curve.
+                let y = point.to_y_coordinate() + Field::one();
+                let residual = Group::curve_residual(&point.to_x_coordinate(), &y);
+                assert!(!residual.eject_value().is_zero());
+            }
+            // Ensure the identity is on the curve.
+            let zero = Group::<Circuit>::zero();
+            assert!(Group::curve_residual(&zero.to_x_coordinate(), &zero.to_y_coordinate()).eject_value().is_zero());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
     #[test]
     fn test_display() {
         let mut rng = TestRng::default();
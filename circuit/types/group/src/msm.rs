@@ -0,0 +1,157 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The number of scalar bits processed per window in a multi-scalar multiplication.
+const MSM_WINDOW_SIZE: usize = 4;
+
+impl<E: Environment> Group<E> {
+    /// Returns the multi-scalar multiplication `sum(scalars[i] * bases[i])`.
+    ///
+    /// This method uses a windowed approach, where each base is expanded into a table of its first
+    /// `2^MSM_WINDOW_SIZE` multiples, and the doublings of the accumulator are shared across all bases.
+    /// If the inputs are empty, this method returns zero.
+    pub fn msm(scalars: &[Scalar<E>], bases: &[Group<E>]) -> Group<E> {
+        // Ensure the number of scalars matches the number of bases.
+        if scalars.len() != bases.len() {
+            E::halt(format!("Mismatched number of scalars ({}) and bases ({}) in MSM", scalars.len(), bases.len()))
+        }
+
+        // Construct the table of multiples `[0, B, 2B, ..., (2^MSM_WINDOW_SIZE - 1)B]` for each base.
+        let tables = bases
+            .iter()
+            .map(|base| {
+                let mut table = Vec::with_capacity(1 << MSM_WINDOW_SIZE);
+                table.push(Group::zero());
+                table.push(base.clone());
+                for i in 2..(1 << MSM_WINDOW_SIZE) {
+                    table.push(&table[i - 1] + base);
+                }
+                table
+            })
+            .collect::<Vec<_>>();
+
+        // Retrieve the big-endian bits of each scalar, padded to a multiple of `MSM_WINDOW_SIZE`.
+        let num_bits = console::Scalar::<E::Network>::size_in_bits();
+        let num_padding_bits = (MSM_WINDOW_SIZE - num_bits % MSM_WINDOW_SIZE) % MSM_WINDOW_SIZE;
+        let scalars_bits_be = scalars
+            .iter()
+            .map(|scalar| {
+                let mut bits_be = vec![Boolean::constant(false); num_padding_bits];
+                bits_be.extend(scalar.to_bits_be());
+                bits_be
+            })
+            .collect::<Vec<_>>();
+
+        // Compute the sum, from the most significant window to the least significant window.
+        let mut output = Group::zero();
+        for i in 0..(num_bits + num_padding_bits) / MSM_WINDOW_SIZE {
+            // Shift the accumulator by one window, except for the first window.
+            if i > 0 {
+                for _ in 0..MSM_WINDOW_SIZE {
+                    output = output.double();
+                }
+            }
+            // Add the selected multiple of each base.
+            for (bits_be, table) in scalars_bits_be.iter().zip_eq(&tables) {
+                let window_bits_be = &bits_be[i * MSM_WINDOW_SIZE..(i + 1) * MSM_WINDOW_SIZE];
+                output += Self::select_multiple(table, window_bits_be);
+            }
+        }
+        output
+    }
+
+    /// Returns the entry of the given table at the index given by the big-endian bits.
+    fn select_multiple(table: &[Group<E>], index_bits_be: &[Boolean<E>]) -> Group<E> {
+        // Select between each pair of entries with the least significant bit, and repeat with the remaining bits.
+        let mut entries = table.to_vec();
+        for bit in index_bits_be.iter().rev() {
+            entries = entries.chunks(2).map(|pair| Group::ternary(bit, &pair[1], &pair[0])).collect();
+        }
+        debug_assert_eq!(entries.len(), 1);
+        entries.swap_remove(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 10;
+    const NUM_PAIRS: usize = 4;
+
+    fn check_msm(mode: Mode, num_constants: u64, num_public: u64, num_private: u64, num_constraints: u64) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample the scalars and bases.
+            let scalars: Vec<console::Scalar<<Circuit as Environment>::Network>> =
+                (0..NUM_PAIRS).map(|_| Uniform::rand(&mut rng)).collect();
+            let bases: Vec<console::Group<<Circuit as Environment>::Network>> =
+                (0..NUM_PAIRS).map(|_| Uniform::rand(&mut rng)).collect();
+
+            // Compute the expected sum natively.
+            let expected =
+                scalars.iter().zip_eq(&bases).fold(console::Group::zero(), |sum, (scalar, base)| sum + *base * *scalar);
+
+            // Prepare the circuit scalars and bases.
+            let circuit_scalars =
+                scalars.iter().map(|scalar| Scalar::<Circuit>::new(mode, *scalar)).collect::<Vec<_>>();
+            let circuit_bases = bases.iter().map(|base| Group::<Circuit>::new(mode, *base)).collect::<Vec<_>>();
+
+            Circuit::scope(format!("MSM {mode} {i}"), || {
+                let candidate = Group::msm(&circuit_scalars, &circuit_bases);
+                assert_eq!(expected, candidate.eject_value());
+                match mode {
+                    // Note: The number of constants depends on the values, so this defines an upper bound.
+                    Mode::Constant => assert_scope!(<=num_constants, num_public, num_private, num_constraints),
+                    _ => assert_scope!(num_constants, num_public, num_private, num_constraints),
+                }
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_msm_constant() {
+        check_msm(Mode::Constant, 3000, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_msm_public() {
+        check_msm(Mode::Public, 862, 0, 12638, 12646);
+    }
+
+    #[test]
+    fn test_msm_private() {
+        check_msm(Mode::Private, 862, 0, 12638, 12646);
+    }
+
+    #[test]
+    fn test_msm_empty() {
+        let candidate = Group::<Circuit>::msm(&[], &[]);
+        assert_eq!(console::Group::zero(), candidate.eject_value());
+        assert!(candidate.is_constant());
+    }
+
+    #[test]
+    fn test_msm_length_mismatch() {
+        let scalars = vec![Scalar::<Circuit>::constant(console::Scalar::one())];
+        let result = std::panic::catch_unwind(|| Group::msm(&scalars, &[]));
+        let message = result.err().and_then(|error| error.downcast_ref::<String>().cloned());
+        assert_eq!(Some("Mismatched number of scalars (1) and bases (0) in MSM".to_string()), message);
+    }
+}
@@ -0,0 +1,157 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Scalar<E> {
+    /// Casts a scalar from a base field, reducing the field element modulo the scalar field modulus.
+    ///
+    /// Unlike `Scalar::from_field`, this method never fails, and unlike `Scalar::from_field_lossy`,
+    /// it preserves the value of every field element that is already less than the scalar field modulus.
+    pub fn from_field_mod(field: &Field<E>) -> Self {
+        // Note: We are reconstituting the integer from the base field.
+        // This is safe as the number of bits in the integer is less than the base field modulus,
+        // and thus will always fit within a single base field element.
+        debug_assert!(E::ScalarField::size_in_bits() < E::BaseField::size_in_bits());
+
+        // If the field element is a constant, reduce it natively.
+        if field.is_constant() {
+            return Scalar::new(Mode::Constant, console::Scalar::from_field_mod(&field.eject_value()));
+        }
+
+        // Retrieve the scalar field size.
+        let size_in_bits = console::Scalar::<E::Network>::size_in_bits();
+
+        // Initialize the scalar field modulus as a (native) base field element.
+        let modulus: console::Field<E::Network> =
+            match console::FromBits::from_bits_le(&E::ScalarField::modulus().to_bits_le()) {
+                Ok(modulus) => modulus,
+                Err(error) => E::halt(format!("Failed to retrieve the scalar modulus as bytes: {error}")),
+            };
+
+        // Compute the largest quotient `K` and the multiple `K * MODULUS`, such that `K * MODULUS <= BaseField::MODULUS - 1`.
+        let (max_quotient, max_multiple) = {
+            let (mut quotient, mut multiple) = (1u64, modulus);
+            while multiple < multiple + modulus {
+                quotient += 1;
+                multiple += modulus;
+            }
+            (quotient, multiple)
+        };
+        // Compute the largest remainder that may accompany the largest quotient.
+        // (For advanced users) BaseField::MODULUS - 1 is equivalent to -1 in the field.
+        let max_remainder = -console::Field::<E::Network>::one() - max_multiple;
+
+        // Determine the number of bits needed to represent the quotient.
+        let num_quotient_bits = (u64::BITS - max_quotient.leading_zeros()) as usize;
+
+        // Witness the little-endian bits of the remainder, followed by the little-endian bits of the quotient.
+        let bits_le: Vec<Boolean<E>> = witness!(|field| {
+            // Compute the quotient and remainder by repeated subtraction, as the quotient is small.
+            let (mut quotient, mut remainder) = (0u64, field);
+            while remainder >= modulus {
+                quotient += 1;
+                remainder -= modulus;
+            }
+            let mut bits_le = remainder.to_bits_le()[..size_in_bits].to_vec();
+            bits_le.extend((0..num_quotient_bits).map(|i| (quotient >> i) & 1 == 1));
+            bits_le
+        });
+
+        // Assert `(quotient, remainder) <= (K, BaseField::MODULUS - 1 - K * MODULUS)` in lexicographic order,
+        // which ensures `quotient * MODULUS + remainder` does not wrap around the base field modulus.
+        let mut max_bits_le = max_remainder.to_bits_le()[..size_in_bits].to_vec();
+        max_bits_le.extend((0..num_quotient_bits).map(|i| (max_quotient >> i) & 1 == 1));
+        Boolean::assert_less_than_or_equal_constant(&bits_le, &max_bits_le);
+
+        // Reconstruct the remainder, which asserts `remainder < MODULUS`.
+        let remainder = Scalar::from_bits_le(&bits_le[..size_in_bits]);
+        // Reconstruct the quotient.
+        let quotient = Field::from_bits_le(&bits_le[size_in_bits..]);
+
+        // Ensure `field == quotient * MODULUS + remainder`.
+        E::assert_eq(field, quotient * Field::constant(modulus) + remainder.to_field());
+
+        remainder
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_from_field_mod(mode: Mode, rng: &mut TestRng) {
+        for i in 0..ITERATIONS {
+            // Sample a random scalar.
+            let expected = Uniform::rand(rng);
+            let candidate = Scalar::<Circuit>::new(mode, expected).to_field();
+
+            Circuit::scope(format!("{mode} {expected} {i}"), || {
+                // Perform the operation.
+                let candidate = Scalar::<Circuit>::from_field_mod(&candidate);
+                assert_eq!(expected, candidate.eject_value());
+                match mode {
+                    Mode::Constant => assert_scope!(1, 0, 0, 0),
+                    _ => assert_scope!(1, 0, 757, 760),
+                }
+            });
+            Circuit::reset();
+
+            // Sample a random field.
+            let given = console::Field::<<Circuit as Environment>::Network>::rand(rng);
+            let expected = console::Scalar::from_field_mod(&given);
+            let candidate = Field::<Circuit>::new(mode, given);
+
+            Circuit::scope(format!("{mode} {given} {i}"), || {
+                // Perform the operation.
+                let candidate = Scalar::<Circuit>::from_field_mod(&candidate);
+                assert_eq!(expected, candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_field_mod() {
+        let mut rng = TestRng::default();
+
+        check_from_field_mod(Mode::Constant, &mut rng);
+        check_from_field_mod(Mode::Public, &mut rng);
+        check_from_field_mod(Mode::Private, &mut rng);
+    }
+
+    #[test]
+    fn test_from_field_mod_out_of_range() {
+        // Construct the largest field element, which exceeds the scalar field modulus.
+        let given = -console::Field::<<Circuit as Environment>::Network>::one();
+        let expected = console::Scalar::from_field_mod(&given);
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Ensure the checked cast rejects the field element.
+            let result = std::panic::catch_unwind(|| Scalar::<Circuit>::from_field(Field::new(mode, given)));
+            assert!(result.is_err() || !Circuit::is_satisfied());
+            Circuit::reset();
+
+            // Ensure the reduced cast matches the native reduction.
+            let candidate = Scalar::<Circuit>::from_field_mod(&Field::new(mode, given));
+            assert_eq!(expected, candidate.eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+}
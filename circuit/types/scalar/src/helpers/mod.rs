@@ -17,6 +17,7 @@ use super::*;
 pub mod from_bits;
 pub mod from_field;
 pub mod from_field_lossy;
+pub mod from_field_mod;
 pub mod one;
 pub mod to_bits;
 pub mod to_field;
@@ -44,39 +44,8 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPHasher<E,
 
     /// Initializes a new instance of BHP with the given domain.
     pub fn setup(domain: &str) -> Result<Self> {
-        // Calculate the maximum window size.
-        let mut maximum_window_size = 0;
-        let mut range = E::BigInteger::from(2_u64);
-        while range < E::Scalar::modulus_minus_one_div_two() {
-            // range < (p-1)/2
-            range.muln(4); // range * 2^4
-            maximum_window_size += 1;
-        }
-        ensure!(WINDOW_SIZE <= maximum_window_size, "The maximum BHP window size is {maximum_window_size}");
-
         // Compute the bases.
-        let bases = (0..NUM_WINDOWS)
-            .map(|index| {
-                // Construct an indexed message to attempt to sample a base.
-                let (generator, _, _) = Blake2Xs::hash_to_curve::<E::Affine>(&format!(
-                    "Aleo.BHP.{NUM_WINDOWS}.{WINDOW_SIZE}.{domain}.{index}"
-                ));
-                let mut base = Group::<E>::new(generator);
-                // Compute the generators for the sampled base.
-                let mut powers = Vec::with_capacity(WINDOW_SIZE as usize);
-                for _ in 0..WINDOW_SIZE {
-                    powers.push(base);
-                    for _ in 0..4 {
-                        base = base.double();
-                    }
-                }
-                powers
-            })
-            .collect::<Vec<Vec<Group<E>>>>();
-        ensure!(bases.len() == NUM_WINDOWS as usize, "Incorrect number of BHP windows ({})", bases.len());
-        for window in &bases {
-            ensure!(window.len() == WINDOW_SIZE as usize, "Incorrect BHP window size ({})", window.len());
-        }
+        let bases = bhp_generators::<E, NUM_WINDOWS, WINDOW_SIZE>(domain)?;
 
         // Compute the bases lookup.
         let bases_lookup = bases
@@ -135,3 +104,47 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPHasher<E,
         &self.random_base
     }
 }
+
+/// Returns the BHP generators for the given domain, as `NUM_WINDOWS` windows of `WINDOW_SIZE` bases,
+/// where each base in a window is the previous base doubled 4 times.
+///
+/// This is the single derivation of the BHP generators, which the native BHP hasher consumes in `setup`,
+/// and the circuit BHP hasher consumes through the native BHP hasher, so the generators are identical by construction.
+pub fn bhp_generators<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8>(
+    domain: &str,
+) -> Result<Vec<Vec<Group<E>>>> {
+    // Calculate the maximum window size.
+    let mut maximum_window_size = 0;
+    let mut range = E::BigInteger::from(2_u64);
+    while range < E::Scalar::modulus_minus_one_div_two() {
+        // range < (p-1)/2
+        range.muln(4); // range * 2^4
+        maximum_window_size += 1;
+    }
+    ensure!(WINDOW_SIZE <= maximum_window_size, "The maximum BHP window size is {maximum_window_size}");
+
+    // Compute the bases.
+    let bases = (0..NUM_WINDOWS)
+        .map(|index| {
+            // Construct an indexed message to attempt to sample a base.
+            let (generator, _, _) =
+                Blake2Xs::hash_to_curve::<E::Affine>(&format!("Aleo.BHP.{NUM_WINDOWS}.{WINDOW_SIZE}.{domain}.{index}"));
+            let mut base = Group::<E>::new(generator);
+            // Compute the generators for the sampled base.
+            let mut powers = Vec::with_capacity(WINDOW_SIZE as usize);
+            for _ in 0..WINDOW_SIZE {
+                powers.push(base);
+                for _ in 0..4 {
+                    base = base.double();
+                }
+            }
+            powers
+        })
+        .collect::<Vec<Vec<Group<E>>>>();
+    ensure!(bases.len() == NUM_WINDOWS as usize, "Incorrect number of BHP windows ({})", bases.len());
+    for window in &bases {
+        ensure!(window.len() == WINDOW_SIZE as usize, "Incorrect BHP window size ({})", window.len());
+    }
+
+    Ok(bases)
+}
@@ -60,14 +60,23 @@ pub struct BHP<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> {
 impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
     /// Initializes a new instance of BHP with the given domain.
     pub fn setup(domain: &str) -> Result<Self> {
+        // Convert the domain into the domain separator.
+        let domain_bits = Self::domain_bits(domain)?;
+
+        // Initialize the BHP hasher.
+        let hasher = BHPHasher::<E, NUM_WINDOWS, WINDOW_SIZE>::setup(domain)?;
+
+        Ok(Self { domain: domain_bits, hasher })
+    }
+
+    /// Returns the domain separator for the given domain, as the domain bits padded with zeros
+    /// and reversed, so that it is: [ 0...0 || DOMAIN ].
+    pub fn domain_bits(domain: &str) -> Result<Vec<bool>> {
         // Ensure the given domain is within the allowed size in bits.
         let num_bits = domain.len().saturating_mul(8);
         let max_bits = Field::<E>::size_in_data_bits() - 64; // 64 bits encode the length.
         ensure!(num_bits <= max_bits, "Domain cannot exceed {max_bits} bits, found {num_bits} bits");
 
-        // Initialize the BHP hasher.
-        let hasher = BHPHasher::<E, NUM_WINDOWS, WINDOW_SIZE>::setup(domain)?;
-
         // Convert the domain into a boolean vector.
         let mut domain = domain.as_bytes().to_bits_le();
         // Pad the domain with zeros up to the maximum size in bits.
@@ -76,7 +85,7 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WI
         // (For advanced users): This optimizes the initial costs during hashing.
         domain.reverse();
 
-        Ok(Self { domain, hasher })
+        Ok(domain)
     }
 
     /// Returns the domain separator for the BHP hash function.
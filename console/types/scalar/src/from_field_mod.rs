@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Scalar<E> {
+    /// Casts a scalar from a base field, reducing the field element modulo the scalar field modulus.
+    ///
+    /// Unlike `Scalar::from_field`, this method never fails, and unlike `Scalar::from_field_lossy`,
+    /// it preserves the value of every field element that is already less than the scalar field modulus.
+    pub fn from_field_mod(field: &Field<E>) -> Self {
+        // Note: The base field modulus is larger than the scalar field modulus,
+        // so the reduction is always well-defined.
+        debug_assert!(Scalar::<E>::size_in_bits() < Field::<E>::size_in_bits());
+
+        // Reconstitute the field bits in the scalar field, which reduces them modulo the scalar field modulus.
+        field.to_bits_le().iter().rev().fold(Self::zero(), |output, bit| match bit {
+            true => output.double() + Self::one(),
+            false => output.double(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 10_000;
+
+    #[test]
+    fn test_from_field_mod() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random scalar.
+            let expected = Scalar::<CurrentEnvironment>::rand(&mut rng);
+            // Perform the operation.
+            let candidate = Scalar::from_field_mod(&expected.to_field()?);
+            assert_eq!(expected, candidate);
+
+            // Sample a random field.
+            let field = Field::<CurrentEnvironment>::rand(&mut rng);
+            // Perform the operation.
+            let candidate = Scalar::<CurrentEnvironment>::from_field_mod(&field);
+            // Ensure the field element is the candidate plus a (small) multiple of the scalar field modulus.
+            let modulus = (-Scalar::<CurrentEnvironment>::one()).to_field()? + Field::one();
+            let mut remainder = candidate.to_field()?;
+            while remainder != field {
+                assert!(remainder < remainder + modulus, "The field element is not congruent to the candidate");
+                remainder += modulus;
+            }
+
+            // If the field element is within the scalar field, the checked cast must agree.
+            if let Ok(checked) = Scalar::from_field(&field) {
+                assert_eq!(checked, candidate);
+            }
+        }
+        Ok(())
+    }
+}
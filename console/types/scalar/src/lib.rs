@@ -22,6 +22,7 @@ mod compare;
 mod from_bits;
 mod from_field;
 mod from_field_lossy;
+mod from_field_mod;
 mod one;
 mod parse;
 mod random;